@@ -1,58 +1,258 @@
 use chrono::{DateTime, Local};
 use colored::*;
-use humantime::format_duration;
+use humantime::{format_duration, parse_duration};
+use libc::{waitpid, WNOHANG};
+use nvml_wrapper::Nvml;
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
-use signal_hook::{consts::SIGTERM, iterator::Signals};
+use signal_hook::{
+    consts::{SIGCHLD, SIGHUP, SIGINT, SIGTERM},
+    iterator::Signals,
+};
 use std::{
+    collections::{HashMap, HashSet},
     env,
     fs::{self, File, OpenOptions},
-    io::{self, BufRead, BufReader, Write},
+    io::{self, Read, Write},
+    os::unix::io::AsRawFd,
+    os::unix::net::{UnixListener, UnixStream},
+    os::unix::process::CommandExt,
     path::PathBuf,
     process::Command,
     sync::atomic::{AtomicBool, Ordering},
-    sync::Arc,
+    sync::{Arc, Mutex},
     thread,
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
+// Serializes `Option<SystemTime>` as Unix seconds so `Job` can round-trip through the state store.
+mod system_time_serde {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    pub fn serialize<S>(time: &Option<SystemTime>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let secs = time.map(|t| t.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs());
+        secs.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<SystemTime>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let secs: Option<u64> = Option::deserialize(deserializer)?;
+        Ok(secs.map(|s| UNIX_EPOCH + Duration::from_secs(s)))
+    }
+}
+
 // Data structures
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 struct Job {
     id: String,
     command: String,
+    #[serde(with = "system_time_serde")]
     start_time: Option<SystemTime>,
-    gpu_index: Option<usize>,
+    #[serde(with = "system_time_serde")]
+    end_time: Option<SystemTime>,
+    gpu_indices: Vec<usize>,
     screen_session: Option<String>,
     status: JobStatus,
     log_dir: Option<PathBuf>,
     env_vars: Vec<(String, String)>,
+    depends_on: Vec<String>,
+    gpus_required: usize,
+    min_mem_mib: u64,
+    max_retries: u32,
+    retry_count: u32,
+    #[serde(with = "system_time_serde")]
+    next_eligible: Option<SystemTime>,
+    node: Option<String>,
+    cancelled: bool,
+    #[serde(with = "system_time_serde")]
+    last_active: Option<SystemTime>,
+    last_seen_mem_mib: Option<u64>,
+    stall_warned: bool,
+    extra_env: Vec<(String, String)>,
+    cwd: Option<String>,
 }
 
+#[derive(Clone)]
 struct Config {
     log_dir: PathBuf,
     jobs_file: PathBuf,
+    state_file: PathBuf,
     refresh_rate: u64,
     _colors_enabled: bool, // This indicates it's currently unused
     datetime_format: String,
+    min_free_memory_mib: u64,
+    max_jobs_per_gpu: usize,
+    default_max_retries: u32,
+    nodes: Vec<Node>,
+    base_retry_delay_secs: u64,
+    kill_grace_secs: u64,
+    history_db: PathBuf,
+    socket_path: PathBuf,
+    stall_timeout_secs: u64,
+    auto_kill_stalled: bool,
+    scrub_interval_secs: u64,
+    drain_timeout_secs: u64,
 }
 
 #[derive(Debug)]
 struct GpuInfo {
+    node: String,
     index: usize,
     name: String,
     memory_total: u64,
     memory_used: u64,
+    utilization: u32,
+    processes: Vec<GpuProcess>,
+}
+
+// A remote host this service can dispatch jobs to over SSH, in addition to "local".
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct Node {
+    name: String,
+    host: String,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Debug)]
+struct GpuProcess {
+    pid: u32,
+    owner: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 enum JobStatus {
     Queued,
     Running,
+    Paused,
     Completed,
     Failed,
+    // Cut off by the daemon itself (drain timeout or immediate shutdown)
+    // rather than having exited on its own, successfully or not.
+    Interrupted,
 }
 
 // Config management
+// Checks the parsed config for unknown keys and wrong-typed fields, returning
+// every problem found (not just the first) so `nexus config check` and the
+// warnings `load_config` emits on startup can point at each one by name.
+fn validate_config(value: &toml::Value) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    let known_sections = ["paths", "display", "scheduling", "nodes"];
+    if let Some(table) = value.as_table() {
+        for key in table.keys() {
+            if !known_sections.contains(&key.as_str()) {
+                problems.push(format!("unknown top-level key `{}`", key));
+            }
+        }
+    }
+
+    check_section(
+        value,
+        "paths",
+        &[
+            ("log_dir", FieldType::Str),
+            ("jobs_file", FieldType::Str),
+            ("state_file", FieldType::Str),
+            ("history_db", FieldType::Str),
+            ("socket_path", FieldType::Str),
+        ],
+        &mut problems,
+    );
+    check_section(
+        value,
+        "display",
+        &[
+            ("refresh_rate", FieldType::Int),
+            ("colors_enabled", FieldType::Bool),
+            ("datetime_format", FieldType::Str),
+        ],
+        &mut problems,
+    );
+    check_section(
+        value,
+        "scheduling",
+        &[
+            ("min_free_memory_mib", FieldType::Int),
+            ("max_jobs_per_gpu", FieldType::Int),
+            ("default_max_retries", FieldType::Int),
+            ("base_retry_delay_secs", FieldType::Int),
+            ("kill_grace_secs", FieldType::Int),
+            ("stall_timeout_secs", FieldType::Int),
+            ("auto_kill_stalled", FieldType::Bool),
+            ("scrub_interval_secs", FieldType::Int),
+            ("drain_timeout_secs", FieldType::Int),
+        ],
+        &mut problems,
+    );
+
+    if let Some(nodes) = value.get("nodes").and_then(|n| n.as_array()) {
+        for (i, node) in nodes.iter().enumerate() {
+            for key in ["name", "host"] {
+                if node.get(key).and_then(|v| v.as_str()).is_none() {
+                    problems.push(format!("nodes[{}] missing string field `{}`", i, key));
+                }
+            }
+        }
+    }
+
+    problems
+}
+
+#[derive(Clone, Copy)]
+enum FieldType {
+    Str,
+    Int,
+    Bool,
+}
+
+fn check_section(
+    value: &toml::Value,
+    section: &str,
+    fields: &[(&str, FieldType)],
+    problems: &mut Vec<String>,
+) {
+    let Some(table) = value.get(section).and_then(|s| s.as_table()) else {
+        return;
+    };
+
+    let known: Vec<&str> = fields.iter().map(|(name, _)| *name).collect();
+    for key in table.keys() {
+        if !known.contains(&key.as_str()) {
+            problems.push(format!("unknown key `{}.{}`", section, key));
+        }
+    }
+
+    for (name, expected) in fields {
+        let Some(v) = table.get(*name) else { continue };
+        let ok = match expected {
+            FieldType::Str => v.as_str().is_some(),
+            FieldType::Int => v.as_integer().is_some(),
+            FieldType::Bool => v.as_bool().is_some(),
+        };
+        if !ok {
+            let expected_str = match expected {
+                FieldType::Str => "a string",
+                FieldType::Int => "an integer",
+                FieldType::Bool => "a boolean",
+            };
+            problems.push(format!(
+                "{}.{} should be {}, found {}",
+                section,
+                name,
+                expected_str,
+                v.type_str()
+            ));
+        }
+    }
+}
+
 fn load_config() -> io::Result<Config> {
     let home = dirs::home_dir()
         .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Could not find home directory"))?;
@@ -62,24 +262,58 @@ fn load_config() -> io::Result<Config> {
     if !config_path.exists() {
         let default_config = r#"[paths]
 log_dir = "~/.nexus/logs"
-jobs_file = "~/.nexus/jobs.txt"
+jobs_file = "~/.nexus/jobs.txt"  # scratch file used by `nexus edit`
+state_file = "~/.nexus/state.msgpack"  # source of truth for the job queue
+history_db = "~/.nexus/history.db"
+socket_path = "~/.nexus/nexus.sock"  # control socket the running daemon listens on
 
 [display]
 refresh_rate = 5  # Status view refresh in seconds
 colors_enabled = true
 datetime_format = "%Y-%m-%d %H:%M:%S"
+
+[scheduling]
+min_free_memory_mib = 1024  # GPUs below this much free memory are not considered available
+max_jobs_per_gpu = 1  # Raise to pack more than one job onto a single GPU
+default_max_retries = 0  # Retries for jobs that don't set --retries
+base_retry_delay_secs = 30  # Doubled per retry attempt, capped at 1 hour
+kill_grace_secs = 10  # How long `nexus kill` waits after SIGTERM before SIGKILL
+stall_timeout_secs = 600  # Warn when a Running job's GPU(s) show no activity this long
+auto_kill_stalled = false  # Cancel stalled jobs automatically instead of only warning
+scrub_interval_secs = 3600  # How often the daemon auto-triggers a GPU health scrub (see `nexus scrub`)
+drain_timeout_secs = 30  # How long `nexus stop --drain` waits for running jobs before interrupting them
+
+# Additional GPU hosts nexus can dispatch to over SSH, e.g.:
+# [[nodes]]
+# name = "gpu-box-2"
+# host = "user@gpu-box-2.local"
 "#;
         fs::write(&config_path, default_config)?;
     }
 
-    // Read and parse config
+    // Read and parse config. A malformed file shouldn't take the whole
+    // process down with it - report the problem and fall back to built-in
+    // defaults for anything that didn't parse.
     let content = fs::read_to_string(&config_path)?;
-    let config: toml::Value = toml::from_str(&content).map_err(|e| {
-        io::Error::new(
-            io::ErrorKind::InvalidData,
-            format!("Config parse error: {}", e),
-        )
-    })?;
+    let config: toml::Value = match toml::from_str(&content) {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!(
+                "{}",
+                format!(
+                    "Config parse error in {}, falling back to defaults: {}",
+                    config_path.display(),
+                    e
+                )
+                .red()
+            );
+            toml::Value::Table(toml::value::Table::new())
+        }
+    };
+
+    for problem in validate_config(&config) {
+        eprintln!("{}", format!("config: {}", problem).yellow());
+    }
 
     let base_dir = home.join(".nexus");
 
@@ -99,6 +333,22 @@ datetime_format = "%Y-%m-%d %H:%M:%S"
         .map(PathBuf::from)
         .unwrap_or_else(|| base_dir.join("jobs.txt"));
 
+    let state_file = config
+        .get("paths")
+        .and_then(|p| p.get("state_file"))
+        .and_then(|l| l.as_str())
+        .map(|p| p.replace("~", home.to_str().unwrap()))
+        .map(PathBuf::from)
+        .unwrap_or_else(|| base_dir.join("state.msgpack"));
+
+    let history_db = config
+        .get("paths")
+        .and_then(|p| p.get("history_db"))
+        .and_then(|l| l.as_str())
+        .map(|p| p.replace("~", home.to_str().unwrap()))
+        .map(PathBuf::from)
+        .unwrap_or_else(|| base_dir.join("history.db"));
+
     let refresh_rate = config
         .get("display")
         .and_then(|d| d.get("refresh_rate"))
@@ -119,6 +369,90 @@ datetime_format = "%Y-%m-%d %H:%M:%S"
         .unwrap_or("%Y-%m-%d %H:%M:%S")
         .to_string();
 
+    let min_free_memory_mib = config
+        .get("scheduling")
+        .and_then(|s| s.get("min_free_memory_mib"))
+        .and_then(|m| m.as_integer())
+        .map(|m| m as u64)
+        .unwrap_or(1024);
+
+    let max_jobs_per_gpu = config
+        .get("scheduling")
+        .and_then(|s| s.get("max_jobs_per_gpu"))
+        .and_then(|m| m.as_integer())
+        .map(|m| m as usize)
+        .unwrap_or(1);
+
+    let default_max_retries = config
+        .get("scheduling")
+        .and_then(|s| s.get("default_max_retries"))
+        .and_then(|m| m.as_integer())
+        .map(|m| m as u32)
+        .unwrap_or(0);
+
+    let base_retry_delay_secs = config
+        .get("scheduling")
+        .and_then(|s| s.get("base_retry_delay_secs"))
+        .and_then(|m| m.as_integer())
+        .map(|m| m as u64)
+        .unwrap_or(30);
+
+    let kill_grace_secs = config
+        .get("scheduling")
+        .and_then(|s| s.get("kill_grace_secs"))
+        .and_then(|m| m.as_integer())
+        .map(|m| m as u64)
+        .unwrap_or(10);
+
+    let socket_path = config
+        .get("paths")
+        .and_then(|p| p.get("socket_path"))
+        .and_then(|l| l.as_str())
+        .map(|p| p.replace("~", home.to_str().unwrap()))
+        .map(PathBuf::from)
+        .unwrap_or_else(|| base_dir.join("nexus.sock"));
+
+    let stall_timeout_secs = config
+        .get("scheduling")
+        .and_then(|s| s.get("stall_timeout_secs"))
+        .and_then(|m| m.as_integer())
+        .map(|m| m as u64)
+        .unwrap_or(600);
+
+    let auto_kill_stalled = config
+        .get("scheduling")
+        .and_then(|s| s.get("auto_kill_stalled"))
+        .and_then(|m| m.as_bool())
+        .unwrap_or(false);
+
+    let scrub_interval_secs = config
+        .get("scheduling")
+        .and_then(|s| s.get("scrub_interval_secs"))
+        .and_then(|m| m.as_integer())
+        .map(|m| m as u64)
+        .unwrap_or(3600);
+
+    let drain_timeout_secs = config
+        .get("scheduling")
+        .and_then(|s| s.get("drain_timeout_secs"))
+        .and_then(|m| m.as_integer())
+        .map(|m| m as u64)
+        .unwrap_or(30);
+
+    let nodes: Vec<Node> = config
+        .get("nodes")
+        .and_then(|n| n.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|n| {
+                    let name = n.get("name")?.as_str()?.to_string();
+                    let host = n.get("host")?.as_str()?.to_string();
+                    Some(Node { name, host })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
     // Ensure directories exist
     fs::create_dir_all(&log_dir)?;
     if !jobs_file.exists() {
@@ -128,9 +462,22 @@ datetime_format = "%Y-%m-%d %H:%M:%S"
     Ok(Config {
         log_dir,
         jobs_file,
+        state_file,
         refresh_rate,
         _colors_enabled: colors_enabled, // Updated to match the field name
         datetime_format,
+        min_free_memory_mib,
+        max_jobs_per_gpu,
+        default_max_retries,
+        base_retry_delay_secs,
+        nodes,
+        kill_grace_secs,
+        history_db,
+        socket_path,
+        stall_timeout_secs,
+        auto_kill_stalled,
+        scrub_interval_secs,
+        drain_timeout_secs,
     })
 }
 
@@ -151,31 +498,62 @@ fn create_job(command: String) -> Job {
         id: generate_job_id(),
         command,
         start_time: None,
-        gpu_index: None,
+        end_time: None,
+        gpu_indices: Vec::new(),
         screen_session: None,
         status: JobStatus::Queued,
         log_dir: None,
         env_vars: Vec::new(),
+        depends_on: Vec::new(),
+        gpus_required: 1,
+        min_mem_mib: 0,
+        max_retries: 0,
+        retry_count: 0,
+        next_eligible: None,
+        node: None,
+        cancelled: false,
+        last_active: None,
+        last_seen_mem_mib: None,
+        stall_warned: false,
+        extra_env: Vec::new(),
+        cwd: None,
     }
 }
 
-fn start_job(job: &mut Job, gpu_index: usize, config: &Config) -> io::Result<()> {
+fn start_job(job: &mut Job, gpu_indices: Vec<usize>, config: &Config) -> io::Result<()> {
     let session_name = format!("nexus_job_{}", job.id);
     let log_dir = config.log_dir.join(&job.id);
     fs::create_dir_all(&log_dir)?;
 
+    let visible_devices = gpu_indices
+        .iter()
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+
     let mut env_vars = vec![
-        ("CUDA_VISIBLE_DEVICES".to_string(), gpu_index.to_string()),
+        ("CUDA_VISIBLE_DEVICES".to_string(), visible_devices),
         ("NEXUS_JOB_ID".to_string(), job.id.clone()),
-        ("NEXUS_GPU_ID".to_string(), gpu_index.to_string()),
     ];
     env_vars.extend(std::env::vars().filter(|(k, _)| !k.starts_with("SCREEN_")));
+    // Job-specific env (e.g. from a manifest's `[defaults.env]`/per-job `env`)
+    // is applied last so it wins over anything inherited from this process.
+    env_vars.extend(job.extra_env.clone());
+
+    let cd_prefix = job
+        .cwd
+        .as_ref()
+        .map(|dir| format!("cd '{}' && ", dir.replace('\'', "'\\''")))
+        .unwrap_or_default();
 
     let command = format!(
-        "exec 1> {} 2> {}; {}",
+        "echo $$ > {}; exec 1> {} 2> {}; {}{}; echo $? > {}",
+        log_dir.join("pid").display(),
         log_dir.join("stdout.log").display(),
         log_dir.join("stderr.log").display(),
-        job.command
+        cd_prefix,
+        job.command,
+        log_dir.join("exit_code").display()
     );
 
     let env_vars_str = env_vars
@@ -195,7 +573,7 @@ fn start_job(job: &mut Job, gpu_index: usize, config: &Config) -> io::Result<()>
         .output()?;
 
     job.start_time = Some(SystemTime::now());
-    job.gpu_index = Some(gpu_index);
+    job.gpu_indices = gpu_indices;
     job.screen_session = Some(session_name);
     job.status = JobStatus::Running;
     job.log_dir = Some(log_dir);
@@ -206,85 +584,222 @@ fn start_job(job: &mut Job, gpu_index: usize, config: &Config) -> io::Result<()>
 
 // File operations
 fn load_jobs(config: &Config) -> io::Result<Vec<Job>> {
-    let file = File::open(&config.jobs_file)?;
-    let reader = BufReader::new(file);
-    let mut jobs = Vec::new();
+    let mut jobs: Vec<Job> = if config.state_file.exists() {
+        let bytes = fs::read(&config.state_file)?;
+        // `lock_state_file` creates the file empty before the first save.
+        if bytes.is_empty() {
+            Vec::new()
+        } else {
+            rmp_serde::from_slice(&bytes)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?
+        }
+    } else {
+        Vec::new()
+    };
 
-    for line in reader.lines() {
-        let command = line?;
-        if !command.trim().is_empty() && !command.trim().starts_with('#') {
-            jobs.push(create_job(command));
+    // Reconcile against surviving screen sessions: update jobs we still recognize
+    // and pick up any we lost track of (e.g. the service restarted mid-run).
+    for recovered in recover_running_jobs()? {
+        if let Some(job) = jobs.iter_mut().find(|j| j.id == recovered.id) {
+            job.gpu_indices = recovered.gpu_indices;
+            job.screen_session = recovered.screen_session;
+            job.status = JobStatus::Running;
+        } else {
+            jobs.push(recovered);
         }
     }
 
-    // Load running jobs from screen sessions
-    let running_jobs = recover_running_jobs()?;
-    jobs.extend(running_jobs);
-
     Ok(jobs)
 }
 
 fn save_jobs(jobs: &[Job], config: &Config) -> io::Result<()> {
-    let mut file = OpenOptions::new()
-        .write(true)
-        .truncate(true)
-        .create(true)
-        .open(&config.jobs_file)?;
+    let bytes = rmp_serde::to_vec(jobs)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+    fs::write(&config.state_file, bytes)
+}
 
-    for job in jobs.iter().filter(|j| j.status == JobStatus::Queued) {
-        writeln!(file, "{}", job.command)?;
+// Holds an exclusive flock on `state_file` for the lifetime of the returned
+// `File`, so a load_jobs -> mutate -> save_jobs pass can't interleave with
+// another process's (daemon or CLI) pass over the same file. The lock is
+// released automatically when the guard is dropped. Every call site that
+// mutates job state needs to take this before load_jobs and hold it through
+// save_jobs -- add/kill/remove/edit were still racing process_jobs without it.
+fn lock_state_file(config: &Config) -> io::Result<File> {
+    let file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .open(&config.state_file)?;
+    let ret = unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX) };
+    if ret != 0 {
+        return Err(io::Error::last_os_error());
     }
-    Ok(())
+    Ok(file)
 }
 
 // GPU management
-fn get_gpu_info() -> io::Result<Vec<GpuInfo>> {
+// Resolves a PID to its owning username via `ps`, since NVML only reports the PID
+// of a compute process, not who started it.
+fn process_owner(pid: u32) -> Option<String> {
+    Command::new("ps")
+        .args(["-o", "user=", "-p", &pid.to_string()])
+        .output()
+        .ok()
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+// Resolves a PID to its process-group id, the same way `process_owner` looks
+// up a username. The job's command runs as a descendant of the `bash -c`
+// wrapper `start_job` launches without `setsid`, so it inherits that
+// wrapper's process group -- comparing groups lets us recognize "this GPU
+// process belongs to one of our own jobs" even though the PID NVML reports
+// is rarely the wrapper's own $$.
+fn process_group(pid: u32) -> Option<u32> {
+    Command::new("ps")
+        .args(["-o", "pgid=", "-p", &pid.to_string()])
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8_lossy(&output.stdout).trim().parse().ok())
+}
+
+// The pid `start_job` wrote to `<log_dir>/pid` for this job, i.e. the `bash
+// -c` wrapper's own $$.
+fn job_root_pid(job: &Job) -> Option<u32> {
+    let log_dir = job.log_dir.as_ref()?;
+    fs::read_to_string(log_dir.join("pid"))
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+// A GPU looks "in use" the instant a job we dispatched to it starts, since
+// NVML reports that job's own compute process right alongside anyone else's.
+// Without this, a GPU could never receive a second packed job once the first
+// one started, which would make `max_jobs_per_gpu > 1` dead on arrival. A
+// process only keeps a GPU unavailable if its process group doesn't belong
+// to one of our own Running/Paused jobs already assigned there.
+fn has_foreign_process(gpu: &GpuInfo, jobs: &[Job]) -> bool {
+    if gpu.processes.is_empty() {
+        return false;
+    }
+    let own_groups: HashSet<u32> = jobs
+        .iter()
+        .filter(|j| matches!(j.status, JobStatus::Running | JobStatus::Paused))
+        .filter(|j| {
+            j.node.as_deref().unwrap_or("local") == gpu.node && j.gpu_indices.contains(&gpu.index)
+        })
+        .filter_map(job_root_pid)
+        .filter_map(process_group)
+        .collect();
+    gpu.processes
+        .iter()
+        .any(|p| process_group(p.pid).map_or(true, |pg| !own_groups.contains(&pg)))
+}
+
+fn get_gpu_info(config: &Config) -> io::Result<Vec<GpuInfo>> {
     if env::var("NEXUS_DEV").is_ok() {
+        let jobs = load_jobs(config).unwrap_or_default();
+        // Real NVML reports a job's own compute process right alongside anyone
+        // else's (see `has_foreign_process`) -- mirror that here instead of
+        // leaving mock GPUs permanently process-free, or packing bugs like
+        // the one `has_foreign_process` guards against would never show up
+        // against this mock in `nexus __selftest`.
+        let mock_processes = |index: usize| -> Vec<GpuProcess> {
+            jobs.iter()
+                .filter(|j| {
+                    matches!(j.status, JobStatus::Running | JobStatus::Paused)
+                        && j.node.as_deref().unwrap_or("local") == "local"
+                        && j.gpu_indices.contains(&index)
+                })
+                .filter_map(job_root_pid)
+                .map(|pid| GpuProcess {
+                    pid,
+                    owner: process_owner(pid).unwrap_or_else(|| "unknown".to_string()),
+                })
+                .collect()
+        };
         return Ok(vec![
             GpuInfo {
+                node: "local".to_string(),
                 index: 0,
                 name: "Mock GPU 0".to_string(),
                 memory_total: 8192,
                 memory_used: 2048,
+                utilization: 0,
+                processes: mock_processes(0),
             },
             GpuInfo {
+                node: "local".to_string(),
                 index: 1,
                 name: "Mock GPU 1".to_string(),
                 memory_total: 16384,
                 memory_used: 4096,
+                utilization: 0,
+                processes: mock_processes(1),
             },
         ]);
     }
 
-    let output = Command::new("nvidia-smi")
-        .args([
-            "--query-gpu=index,name,memory.total,memory.used",
-            "--format=csv,noheader",
-        ])
-        .output()?;
-
-    if !output.status.success() {
-        return Err(io::Error::new(
-            io::ErrorKind::Other,
-            String::from_utf8_lossy(&output.stderr).to_string(),
-        ));
-    }
+    let nvml = Nvml::init().map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+    let device_count = nvml
+        .device_count()
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
 
     let mut gpus = Vec::new();
-    for line in String::from_utf8_lossy(&output.stdout).lines() {
-        let parts: Vec<&str> = line.split(',').collect();
-        if parts.len() == 4 {
-            gpus.push(GpuInfo {
-                index: parts[0].trim().parse().unwrap(),
-                name: parts[1].trim().to_string(),
-                memory_total: parts[2].trim().replace("MiB", "").parse().unwrap(),
-                memory_used: parts[3].trim().replace("MiB", "").parse().unwrap(),
-            });
-        }
+    for index in 0..device_count {
+        let device = nvml
+            .device_by_index(index)
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+        let name = device
+            .name()
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+        let memory = device
+            .memory_info()
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+        let utilization = device
+            .utilization_rates()
+            .map(|u| u.gpu)
+            .unwrap_or(0);
+        let processes = device
+            .running_compute_processes()
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?
+            .into_iter()
+            .map(|p| GpuProcess {
+                pid: p.pid,
+                owner: process_owner(p.pid).unwrap_or_else(|| "unknown".to_string()),
+            })
+            .collect();
+
+        gpus.push(GpuInfo {
+            node: "local".to_string(),
+            index: index as usize,
+            name,
+            memory_total: memory.total / 1024 / 1024,
+            memory_used: memory.used / 1024 / 1024,
+            utilization,
+            processes,
+        });
     }
+
     Ok(gpus)
 }
 
+// A GPU has spare capacity when no other user's compute process is touching it, it
+// isn't already packed up to `max_jobs_per_gpu`, and it has enough free memory left
+// to be useful. This is an approximate, display-only check; the scheduler in
+// `process_jobs` tracks committed reservations precisely within a single pass.
+fn is_gpu_available(gpu: &GpuInfo, jobs: &[Job], config: &Config) -> bool {
+    let nexus_job_count = jobs
+        .iter()
+        .filter(|j| j.status == JobStatus::Running && j.gpu_indices.contains(&gpu.index))
+        .count();
+    if nexus_job_count >= config.max_jobs_per_gpu || has_foreign_process(gpu, jobs) {
+        return false;
+    }
+    gpu.memory_total.saturating_sub(gpu.memory_used) >= config.min_free_memory_mib
+}
+
 // Screen session management
 fn is_job_running(session: &str) -> bool {
     Command::new("screen")
@@ -294,6 +809,179 @@ fn is_job_running(session: &str) -> bool {
         .unwrap_or(false)
 }
 
+// Cluster dispatch
+//
+// A `GpuBackend` hides whether a GPU is queried/launched on directly (the
+// machine nexus itself runs on) or over SSH on a configured `Node`, so the
+// scheduler in `process_jobs` can treat the whole cluster as one pool.
+trait GpuBackend {
+    fn node_name(&self) -> &str;
+    fn gpu_info(&self, config: &Config) -> io::Result<Vec<GpuInfo>>;
+    fn start_job(&self, job: &mut Job, gpu_indices: Vec<usize>, config: &Config) -> io::Result<()>;
+    fn is_job_running(&self, session: &str) -> bool;
+    /// Sends `signal` (e.g. "TERM", "KILL") to the job's recorded process group.
+    fn signal_job(&self, log_dir: &std::path::Path, signal: &str) -> io::Result<()>;
+    fn quit_session(&self, session: &str) -> io::Result<()>;
+}
+
+struct LocalBackend;
+
+impl GpuBackend for LocalBackend {
+    fn node_name(&self) -> &str {
+        "local"
+    }
+
+    fn gpu_info(&self, config: &Config) -> io::Result<Vec<GpuInfo>> {
+        get_gpu_info(config)
+    }
+
+    fn start_job(&self, job: &mut Job, gpu_indices: Vec<usize>, config: &Config) -> io::Result<()> {
+        start_job(job, gpu_indices, config)
+    }
+
+    fn is_job_running(&self, session: &str) -> bool {
+        is_job_running(session)
+    }
+
+    fn signal_job(&self, log_dir: &std::path::Path, signal: &str) -> io::Result<()> {
+        if let Ok(pid) = fs::read_to_string(log_dir.join("pid")) {
+            if let Ok(pid) = pid.trim().parse::<i64>() {
+                // Negative pid targets the whole process group the job's shell leads.
+                Command::new("kill")
+                    .args([&format!("-{}", signal), &format!("-{}", pid)])
+                    .output()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn quit_session(&self, session: &str) -> io::Result<()> {
+        Command::new("screen")
+            .args(["-S", session, "-X", "quit"])
+            .output()?;
+        Ok(())
+    }
+}
+
+struct RemoteBackend<'a> {
+    node: &'a Node,
+}
+
+impl<'a> RemoteBackend<'a> {
+    fn ssh(&self, remote_command: &str) -> io::Result<std::process::Output> {
+        Command::new("ssh")
+            .args([&self.node.host, remote_command])
+            .output()
+    }
+}
+
+impl<'a> GpuBackend for RemoteBackend<'a> {
+    fn node_name(&self) -> &str {
+        &self.node.name
+    }
+
+    fn gpu_info(&self, _config: &Config) -> io::Result<Vec<GpuInfo>> {
+        let output = self.ssh(
+            "nvidia-smi --query-gpu=index,name,memory.total,memory.used,utilization.gpu --format=csv,noheader",
+        )?;
+        if !output.status.success() {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                String::from_utf8_lossy(&output.stderr).to_string(),
+            ));
+        }
+
+        let mut gpus = Vec::new();
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+            if let [index, name, total, used, utilization] = parts[..] {
+                gpus.push(GpuInfo {
+                    node: self.node.name.clone(),
+                    index: index.parse().unwrap_or(0),
+                    name: name.to_string(),
+                    memory_total: total.replace("MiB", "").trim().parse().unwrap_or(0),
+                    memory_used: used.replace("MiB", "").trim().parse().unwrap_or(0),
+                    utilization: utilization.replace('%', "").trim().parse().unwrap_or(0),
+                    processes: Vec::new(),
+                });
+            }
+        }
+        Ok(gpus)
+    }
+
+    fn start_job(&self, job: &mut Job, gpu_indices: Vec<usize>, config: &Config) -> io::Result<()> {
+        let session_name = format!("nexus_job_{}", job.id);
+        let log_dir = config.log_dir.join(&job.id);
+        let visible_devices = gpu_indices
+            .iter()
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let remote_command = format!(
+            "mkdir -p {dir} && screen -dmS {session} bash -c 'export CUDA_VISIBLE_DEVICES=\"{devices}\"; export NEXUS_JOB_ID=\"{id}\"; echo $$ > {dir}/pid; exec 1> {dir}/stdout.log 2> {dir}/stderr.log; {cmd}; echo $? > {dir}/exit_code'",
+            dir = log_dir.display(),
+            session = session_name,
+            devices = visible_devices,
+            id = job.id,
+            cmd = job.command
+        );
+        self.ssh(&remote_command)?;
+
+        job.start_time = Some(SystemTime::now());
+        job.gpu_indices = gpu_indices;
+        job.screen_session = Some(session_name);
+        job.status = JobStatus::Running;
+        job.log_dir = Some(log_dir);
+        job.node = Some(self.node.name.clone());
+
+        Ok(())
+    }
+
+    fn is_job_running(&self, session: &str) -> bool {
+        self.ssh(&format!("screen -ls {}", session))
+            .map(|output| String::from_utf8_lossy(&output.stdout).contains(&format!(".{}", session)))
+            .unwrap_or(false)
+    }
+
+    fn signal_job(&self, log_dir: &std::path::Path, signal: &str) -> io::Result<()> {
+        self.ssh(&format!(
+            "kill -{} -$(cat {}/pid) 2>/dev/null",
+            signal,
+            log_dir.display()
+        ))?;
+        Ok(())
+    }
+
+    fn quit_session(&self, session: &str) -> io::Result<()> {
+        self.ssh(&format!("screen -S {} -X quit", session))?;
+        Ok(())
+    }
+}
+
+fn backends(config: &Config) -> Vec<Box<dyn GpuBackend + '_>> {
+    let mut backends: Vec<Box<dyn GpuBackend>> = vec![Box::new(LocalBackend)];
+    backends.extend(
+        config
+            .nodes
+            .iter()
+            .map(|node| Box::new(RemoteBackend { node }) as Box<dyn GpuBackend>),
+    );
+    backends
+}
+
+fn backend_for<'a>(config: &'a Config, node: Option<&str>) -> Box<dyn GpuBackend + 'a> {
+    match node {
+        None | Some("local") => Box::new(LocalBackend),
+        Some(name) => config
+            .nodes
+            .iter()
+            .find(|n| n.name == name)
+            .map(|node| Box::new(RemoteBackend { node }) as Box<dyn GpuBackend>)
+            .unwrap_or_else(|| Box::new(LocalBackend)),
+    }
+}
+
 // Recovery
 fn recover_running_jobs() -> io::Result<Vec<Job>> {
     let output = Command::new("screen").args(["-ls"]).output()?;
@@ -306,7 +994,7 @@ fn recover_running_jobs() -> io::Result<Vec<Job>> {
             .find(|&s| s.starts_with("nexus_job_"))
         {
             let job_id = session_name.trim_start_matches("nexus_job_");
-            let gpu_index = Command::new("ps")
+            let gpu_indices: Option<Vec<usize>> = Command::new("ps")
                 .args(["aux"])
                 .output()
                 .ok()
@@ -318,14 +1006,17 @@ fn recover_running_jobs() -> io::Result<Vec<Job>> {
                             line.split_whitespace()
                                 .find(|&s| s.starts_with("CUDA_VISIBLE_DEVICES="))
                                 .and_then(|s| s.split('=').nth(1))
-                                .and_then(|s| s.parse().ok())
+                                // start_job exports the value as `"0,1";`
+                                .map(|s| s.trim_matches(|c: char| c == '"' || c == ';'))
+                                .map(|s| s.split(',').filter_map(|i| i.parse().ok()).collect())
                         })
                 });
 
-            if let Some(gpu_idx) = gpu_index {
+            if let Some(indices) = gpu_indices {
                 let mut job = create_job(String::new()); // Command will be empty for recovered jobs
                 job.id = job_id.to_string();
-                job.gpu_index = Some(gpu_idx);
+                job.gpus_required = indices.len().max(1);
+                job.gpu_indices = indices;
                 job.screen_session = Some(session_name.to_string());
                 job.status = JobStatus::Running;
                 jobs.push(job);
@@ -374,18 +1065,117 @@ fn start_service(config: &Config) -> io::Result<()> {
     Ok(())
 }
 
-fn stop_service() -> io::Result<()> {
-    Command::new("screen")
-        .args(["-S", "nexus", "-X", "quit"])
-        .output()?;
-    println!("{}", "Nexus service stopped".green());
-    Ok(())
+// `nexus stop` (or `nexus stop --now`) tears the daemon's screen session down
+// immediately -- no grace period for in-flight jobs. `nexus stop --drain
+// [--timeout N]` instead signals the running daemon to drain (see
+// `run_daemon`'s signal handler), which stops dispatching new jobs, waits up
+// to the drain timeout for running ones to finish on their own, then
+// interrupts any still running and exits.
+enum StopMode {
+    Now,
+    Drain { timeout: Option<u64> },
+}
+
+fn stop_service(mode: StopMode, config: &Config) -> io::Result<()> {
+    match mode {
+        StopMode::Now => {
+            Command::new("screen")
+                .args(["-S", "nexus", "-X", "quit"])
+                .output()?;
+            println!("{}", "Nexus service stopped".green());
+            Ok(())
+        }
+        StopMode::Drain { timeout } => {
+            let Ok(pid) = fs::read_to_string(daemon_pid_path(config)) else {
+                println!("{}", "Nexus service is not running".red());
+                return Ok(());
+            };
+            let pid = pid.trim();
+            if pid.is_empty() {
+                println!("{}", "Nexus service is not running".red());
+                return Ok(());
+            }
+
+            if let Some(secs) = timeout {
+                fs::write(drain_timeout_override_path(config), secs.to_string())?;
+            }
+
+            let status = Command::new("kill").args(["-TERM", pid]).status()?;
+            if status.success() {
+                println!(
+                    "{}",
+                    "Drain requested; the daemon will stop once running jobs finish (or the drain timeout elapses)".green()
+                );
+            } else {
+                println!("{}", "Nexus service is not running".red());
+            }
+            Ok(())
+        }
+    }
+}
+
+// Zero-downtime reload
+//
+// Every bit of state `process_jobs` needs -- each job's pid (via its screen
+// session), GPU assignment, log dir, and start time -- already lives in
+// `state_file`, not in the daemon's memory, and `load_jobs` reconciles it
+// against surviving screen sessions on every read regardless of whether the
+// daemon that wrote it is still the one reading it back. That means the
+// daemon itself doesn't need a separate handoff file to avoid losing
+// in-flight jobs across a reload: `state_file` already is that handoff.
+// So instead of `stop_service` + sleep + `start_service` (a window where
+// nothing dispatches the queue, and briefly two processes *could* exist),
+// `nexus reload` signals the running daemon to `exec()` itself back into
+// `nexus daemon` in place -- same PID, same "nexus" screen session, and at
+// no point are there two daemons alive to double-schedule the same job.
+fn daemon_pid_path(config: &Config) -> PathBuf {
+    config.log_dir.join("daemon.pid")
+}
+
+// `nexus stop --drain --timeout N` drops its override here before signaling
+// the daemon, so the signal handler (which has no way to carry an argument)
+// can pick a timeout other than `config.drain_timeout_secs` for this one stop.
+fn drain_timeout_override_path(config: &Config) -> PathBuf {
+    config.log_dir.join("drain_timeout_override")
+}
+
+fn read_drain_timeout(config: &Config) -> Duration {
+    let path = drain_timeout_override_path(config);
+    let secs = fs::read_to_string(&path)
+        .ok()
+        .and_then(|s| s.trim().parse::<u64>().ok());
+    let _ = fs::remove_file(&path);
+    Duration::from_secs(secs.unwrap_or(config.drain_timeout_secs))
+}
+
+// Sends SIGHUP to the running daemon if one is alive. Returns false (without
+// error) when no daemon is running, so callers like `nexus restart` can fall
+// back to `start_service`.
+fn handle_reload(config: &Config) -> io::Result<bool> {
+    let Ok(pid) = fs::read_to_string(daemon_pid_path(config)) else {
+        return Ok(false);
+    };
+    let pid = pid.trim();
+    if pid.is_empty() {
+        return Ok(false);
+    }
+
+    let status = Command::new("kill").args(["-HUP", pid]).status()?;
+    if status.success() {
+        println!("{}", "Reload signal sent to running daemon".green());
+        Ok(true)
+    } else {
+        Ok(false)
+    }
 }
 
 // Status display
 fn render_status(config: &Config) -> io::Result<()> {
     let jobs = load_jobs(config)?;
-    let gpus = get_gpu_info()?;
+    let gpus: Vec<GpuInfo> = backends(config)
+        .iter()
+        .flat_map(|b| b.gpu_info(config).unwrap_or_default())
+        .collect();
 
     let queued_count = jobs
         .iter()
@@ -403,56 +1193,118 @@ fn render_status(config: &Config) -> io::Result<()> {
         "RUNNING".green()
     };
 
+    let daemon_status = match send_control_request(config, &ControlRequest::Ping) {
+        Ok(ControlResponse::Pong) => "UP".green(),
+        _ => "DOWN".red(),
+    };
+
     println!(
-        "{}: {} jobs pending [{}]",
+        "{}: {} jobs pending [{}] (daemon: {})",
         "Queue".blue().bold(),
         queued_count,
-        queue_status
+        queue_status,
+        daemon_status
     );
     println!(
-        "{}: {} jobs completed\n",
+        "{}: {} jobs completed",
         "History".blue().bold(),
         completed_count
     );
 
+    let scrub = load_scrub_status(config);
+    match scrub.last_run {
+        Some(t) => println!(
+            "{}: last ran {} ago, {} finding(s) [{}]\n",
+            "Scrub".blue().bold(),
+            format_duration(t.elapsed().unwrap_or_default()),
+            scrub.findings.len(),
+            if scrub.enabled { "ENABLED".green() } else { "PAUSED".yellow() }
+        ),
+        None => println!(
+            "{}: never run [{}]\n",
+            "Scrub".blue().bold(),
+            if scrub.enabled { "ENABLED".green() } else { "PAUSED".yellow() }
+        ),
+    }
+
     println!("{}:", "GPUs".white().bold());
     for gpu in gpus {
         let mem_usage = (gpu.memory_used as f64 / gpu.memory_total as f64 * 100.0) as u64;
         println!(
-            "GPU {} ({}, {}MB/{}MB, {}%):",
+            "GPU {}@{} ({}, {}MB/{}MB, {}%):",
             gpu.index.to_string().white(),
+            gpu.node,
             gpu.name,
             gpu.memory_used,
             gpu.memory_total,
             mem_usage
         );
 
-        if let Some(job) = jobs
+        let running_jobs: Vec<_> = jobs
             .iter()
-            .find(|j| j.status == JobStatus::Running && j.gpu_index == Some(gpu.index))
-        {
-            let runtime = job.start_time.map(|t| t.elapsed().unwrap_or_default());
-            let start_time = job
-                .start_time
-                .map(|t| {
-                    DateTime::<Local>::from(t)
-                        .format(&config.datetime_format)
-                        .to_string()
-                })
-                .unwrap_or_else(|| "Unknown".to_string());
+            .filter(|j| {
+                matches!(j.status, JobStatus::Running | JobStatus::Paused)
+                    && j.gpu_indices.contains(&gpu.index)
+                    && j.node.as_deref().unwrap_or("local") == gpu.node
+            })
+            .collect();
+
+        if !running_jobs.is_empty() {
+            for job in running_jobs {
+                let runtime = job.start_time.map(|t| t.elapsed().unwrap_or_default());
+                let start_time = job
+                    .start_time
+                    .map(|t| {
+                        DateTime::<Local>::from(t)
+                            .format(&config.datetime_format)
+                            .to_string()
+                    })
+                    .unwrap_or_else(|| "Unknown".to_string());
 
-            println!("  {}: {}", "Job ID".magenta(), job.id);
-            println!("  {}: {}", "Command".white().bold(), job.command);
+                println!("  {}: {}", "Job ID".magenta(), job.id);
+                if job.status == JobStatus::Paused {
+                    println!("  {}: {}", "Status".yellow(), "PAUSED".yellow());
+                }
+                println!("  {}: {}", "Command".white().bold(), job.command);
+                println!(
+                    "  {}: {}",
+                    "Runtime".cyan(),
+                    format_duration(runtime.expect("Expected runtime"))
+                        .to_string()
+                        .cyan()
+                );
+                println!("  {}: {}", "Started".cyan(), start_time.cyan());
+                if job.status == JobStatus::Running {
+                    if let Some(stalled_for) = job
+                        .last_active
+                        .and_then(|t| SystemTime::now().duration_since(t).ok())
+                        .filter(|d| d.as_secs() >= config.stall_timeout_secs)
+                    {
+                        println!(
+                            "  {}: {}",
+                            "Warning".yellow(),
+                            format!("stalled for {}", format_duration(stalled_for)).yellow()
+                        );
+                    }
+                }
+            }
+        } else if let Some(proc) = gpu.processes.first() {
             println!(
-                "  {}: {}",
-                "Runtime".cyan(),
-                format_duration(runtime.expect("Expected runtime"))
-                    .to_string()
-                    .cyan()
+                "  {}",
+                format!("In use by {} (pid {})", proc.owner, proc.pid).yellow()
             );
-            println!("  {}: {}", "Started".cyan(), start_time.cyan());
-        } else {
+        } else if is_gpu_available(&gpu, &jobs, config) {
             println!("  {}", "Available".bright_green());
+        } else {
+            println!("  {}", "Unavailable (low free memory)".yellow());
+        }
+
+        for finding in scrub
+            .findings
+            .iter()
+            .filter(|f| f.node == gpu.node && f.index == gpu.index)
+        {
+            println!("  {} {}", "Scrub warning:".yellow(), finding.message.yellow());
         }
     }
 
@@ -463,65 +1315,477 @@ fn handle_status(config: &Config) -> io::Result<()> {
     render_status(config)
 }
 
-// Job processing
-fn process_jobs(config: &Config) -> io::Result<()> {
-    let mut jobs = load_jobs(config)?;
-    let gpus = get_gpu_info()?;
+// Reads a finished job's exit code, fetching it over SSH when the job ran on a
+// remote node rather than reading the path directly off the local filesystem.
+fn read_exit_code(job: &Job, config: &Config) -> Option<i32> {
+    let dir = job.log_dir.as_ref()?;
+    let contents = match job.node.as_deref() {
+        None | Some("local") => fs::read_to_string(dir.join("exit_code")).ok()?,
+        Some(node_name) => {
+            let node = config.nodes.iter().find(|n| n.name == node_name)?;
+            let output = Command::new("ssh")
+                .args([&node.host, &format!("cat {}/exit_code", dir.display())])
+                .output()
+                .ok()?;
+            String::from_utf8_lossy(&output.stdout).to_string()
+        }
+    };
+    contents.trim().parse().ok()
+}
 
-    // Update status of running jobs
-    for job in jobs.iter_mut().filter(|j| j.status == JobStatus::Running) {
+// SIGTERM a job's process group (after SIGCONT if it's paused), give it
+// `config.kill_grace_secs` to exit on its own, SIGKILL it if it hasn't, then
+// tear down the screen session. Leaves `job.status` untouched -- callers
+// (`cancel_job`, `interrupt_job`) set the final status themselves since it
+// differs by why the job was torn down.
+fn terminate_job(job: &mut Job, config: &Config) -> io::Result<()> {
+    let backend = backend_for(config, job.node.as_deref());
+    if let Some(log_dir) = job.log_dir.clone() {
+        if job.status == JobStatus::Paused {
+            // A stopped process group won't act on SIGTERM until it's
+            // resumed, so wake it up first.
+            backend.signal_job(&log_dir, "CONT")?;
+        }
+        backend.signal_job(&log_dir, "TERM")?;
+        for _ in 0..config.kill_grace_secs {
+            thread::sleep(Duration::from_secs(1));
+            if !job
+                .screen_session
+                .as_deref()
+                .is_some_and(|s| backend.is_job_running(s))
+            {
+                break;
+            }
+        }
+        if job
+            .screen_session
+            .as_deref()
+            .is_some_and(|s| backend.is_job_running(s))
+        {
+            backend.signal_job(&log_dir, "KILL")?;
+        }
+    }
+    if let Some(session) = &job.screen_session {
+        backend.quit_session(session)?;
+    }
+    Ok(())
+}
+
+// Gracefully cancels a running job. Marks it Failed with `cancelled` set so
+// callers can distinguish a deliberate `nexus kill` from a natural failure.
+fn cancel_job(job: &mut Job, config: &Config) -> io::Result<()> {
+    terminate_job(job, config)?;
+    job.status = JobStatus::Failed;
+    job.cancelled = true;
+    job.end_time = Some(SystemTime::now());
+    record_history(config, job, read_exit_code(job, config))?;
+    Ok(())
+}
+
+// Called when the daemon's drain timeout elapses with jobs still running: the
+// job didn't fail on its own and nobody asked to cancel it, the daemon just
+// ran out of time to wait for it, so it's recorded Interrupted rather than
+// Failed or cancelled.
+fn interrupt_job(job: &mut Job, config: &Config) -> io::Result<()> {
+    terminate_job(job, config)?;
+    job.status = JobStatus::Interrupted;
+    job.end_time = Some(SystemTime::now());
+    record_history(config, job, read_exit_code(job, config))?;
+    Ok(())
+}
+
+// Interrupts every still-running or paused job, used once a drain's timeout
+// elapses so the daemon can finish shutting down instead of waiting forever.
+fn drain_running_jobs(config: &Config) -> io::Result<()> {
+    let _lock = lock_state_file(config)?;
+    let mut jobs = load_jobs(config)?;
+    for job in jobs
+        .iter_mut()
+        .filter(|j| matches!(j.status, JobStatus::Running | JobStatus::Paused))
+    {
+        interrupt_job(job, config)?;
+        log_service_event(
+            config,
+            &format!("Job {} interrupted: drain timeout elapsed", job.id),
+        )?;
+    }
+    save_jobs(&jobs, config)
+}
+
+// Exponential backoff for retries, doubled per attempt and capped at 1 hour.
+fn base_retry_delay_for(config: &Config, retry_count: u32) -> Duration {
+    let secs = config
+        .base_retry_delay_secs
+        .saturating_mul(1u64.checked_shl(retry_count).unwrap_or(u64::MAX));
+    Duration::from_secs(secs.min(3600))
+}
+
+// Job processing
+// `dispatch_new` gates just the "start newly-eligible queued jobs" step --
+// status reconciliation, stall checks, and failure cascades still run either
+// way, so a draining daemon keeps noticing jobs finish without picking up
+// any new ones.
+fn process_jobs(config: &Config, dispatch_new: bool) -> io::Result<()> {
+    let _lock = lock_state_file(config)?;
+    let mut jobs = load_jobs(config)?;
+
+    // Gather GPUs across the whole cluster (local host plus any configured nodes),
+    // skipping nodes that are unreachable rather than aborting the whole pass.
+    let mut gpus = Vec::new();
+    for backend in backends(config) {
+        match backend.gpu_info(config) {
+            Ok(mut node_gpus) => gpus.append(&mut node_gpus),
+            Err(e) => log_service_event(
+                config,
+                &format!("Failed to query GPUs on node {}: {}", backend.node_name(), e),
+            )?,
+        }
+    }
+
+    // Update status of running jobs
+    for job in jobs.iter_mut().filter(|j| j.status == JobStatus::Running) {
         if let Some(session) = &job.screen_session {
-            if !is_job_running(session) {
-                job.status = JobStatus::Completed;
-                log_service_event(
-                    config,
-                    &format!("Job {} completed on GPU {}", job.id, job.gpu_index.unwrap()),
-                )?;
+            let backend = backend_for(config, job.node.as_deref());
+            if !backend.is_job_running(session) {
+                let exit_code = read_exit_code(job, config);
+
+                job.end_time = Some(SystemTime::now());
+
+                if exit_code == Some(0) {
+                    job.status = JobStatus::Completed;
+                    record_history(config, job, exit_code)?;
+                    log_service_event(
+                        config,
+                        &format!("Job {} completed on GPU(s) {:?}", job.id, job.gpu_indices),
+                    )?;
+                } else if job.retry_count < job.max_retries {
+                    job.retry_count += 1;
+                    let delay = base_retry_delay_for(config, job.retry_count);
+                    job.status = JobStatus::Queued;
+                    job.next_eligible = Some(SystemTime::now() + delay);
+                    job.gpu_indices.clear();
+                    job.screen_session = None;
+                    log_service_event(
+                        config,
+                        &format!(
+                            "Job {} failed (exit code {:?}), retrying (attempt {}/{}) in {}",
+                            job.id,
+                            exit_code,
+                            job.retry_count,
+                            job.max_retries,
+                            format_duration(delay)
+                        ),
+                    )?;
+                } else {
+                    job.status = JobStatus::Failed;
+                    record_history(config, job, exit_code)?;
+                    log_service_event(
+                        config,
+                        &format!(
+                            "Job {} failed on GPU(s) {:?} (exit code: {:?})",
+                            job.id, job.gpu_indices, exit_code
+                        ),
+                    )?;
+                }
             }
         }
     }
 
-    // Find available GPUs
-    let available_gpus: Vec<usize> = gpus
-        .iter()
-        .map(|g| g.index)
-        .filter(|&i| {
-            !jobs
-                .iter()
-                .any(|j| j.status == JobStatus::Running && j.gpu_index == Some(i))
-        })
-        .collect();
+    // Heartbeat: a job's assigned GPU(s) showing nonzero utilization or a
+    // change in memory used counts as activity and bumps `last_active`. A
+    // Running job whose GPUs stay at ~0% utilization and flat memory for
+    // `stall_timeout_secs` is logged as stalled (once, via `stall_warned`,
+    // so it doesn't re-log every scheduling pass) and optionally auto-killed.
+    let now = SystemTime::now();
+    for job in jobs.iter_mut().filter(|j| j.status == JobStatus::Running) {
+        let node = job.node.as_deref().unwrap_or("local");
+        let assigned: Vec<&GpuInfo> = gpus
+            .iter()
+            .filter(|g| g.node == node && job.gpu_indices.contains(&g.index))
+            .collect();
+        if assigned.is_empty() {
+            continue;
+        }
+
+        let total_util: u32 = assigned.iter().map(|g| g.utilization).sum();
+        let total_mem: u64 = assigned.iter().map(|g| g.memory_used).sum();
+        let active = total_util > 0 || job.last_seen_mem_mib != Some(total_mem);
+
+        job.last_seen_mem_mib = Some(total_mem);
+        if active || job.last_active.is_none() {
+            job.last_active = Some(now);
+            job.stall_warned = false;
+            continue;
+        }
 
-    // Start jobs on available GPUs
-    for gpu_index in available_gpus {
-        if let Some(job) = jobs.iter_mut().find(|j| j.status == JobStatus::Queued) {
-            if let Err(e) = start_job(job, gpu_index, config) {
-                eprintln!("{}", format!("Failed to start job {}: {}", job.id, e).red());
-                job.status = JobStatus::Failed;
+        let stalled_for = now
+            .duration_since(job.last_active.unwrap())
+            .unwrap_or_default();
+        if stalled_for.as_secs() >= config.stall_timeout_secs {
+            if !job.stall_warned {
                 log_service_event(
                     config,
-                    &format!("Failed to start job {} on GPU {}: {}", job.id, gpu_index, e),
+                    &format!(
+                        "Job {} appears stalled (0% utilization, flat memory for {})",
+                        job.id,
+                        format_duration(stalled_for)
+                    ),
                 )?;
-            } else {
+                job.stall_warned = true;
+            }
+            if config.auto_kill_stalled {
                 log_service_event(
                     config,
-                    &format!(
-                        "Started job {} on GPU {}: {}",
-                        job.id, gpu_index, job.command
-                    ),
+                    &format!("Auto-killing stalled job {}", job.id),
                 )?;
+                cancel_job(job, config)?;
             }
         }
     }
 
+    // Cascade failures onto jobs whose dependencies failed, rather than leaving
+    // them queued forever waiting on a predecessor that will never complete.
+    let failed_ids: Vec<String> = jobs
+        .iter()
+        .filter(|j| j.status == JobStatus::Failed)
+        .map(|j| j.id.clone())
+        .collect();
+    for job in jobs.iter_mut().filter(|j| j.status == JobStatus::Queued) {
+        if job.depends_on.iter().any(|id| failed_ids.contains(id)) {
+            job.status = JobStatus::Failed;
+            record_history(config, job, None)?;
+            log_service_event(
+                config,
+                &format!("Job {} failed: a dependency did not complete", job.id),
+            )?;
+        }
+    }
+
+    // A dependency that no longer exists at all (e.g. removed from the queue
+    // before it ran) can never become Completed, so treat it the same as a
+    // failed dependency rather than leaving the job queued forever.
+    let known_ids: HashSet<String> = jobs.iter().map(|j| j.id.clone()).collect();
+    for job in jobs.iter_mut().filter(|j| j.status == JobStatus::Queued) {
+        if job.depends_on.iter().any(|id| !known_ids.contains(id)) {
+            job.status = JobStatus::Failed;
+            record_history(config, job, None)?;
+            log_service_event(
+                config,
+                &format!("Job {} failed: a dependency no longer exists", job.id),
+            )?;
+        }
+    }
+
+    let completed_ids: Vec<String> = jobs
+        .iter()
+        .filter(|j| j.status == JobStatus::Completed)
+        .map(|j| j.id.clone())
+        .collect();
+
+    // Track memory and job-count reservations committed within this scheduling
+    // pass so two jobs don't double-book the same (node, GPU) before `jobs` is
+    // saved. GPU index 0 on one node is a different device than index 0 on
+    // another, so reservations are keyed by node as well as index.
+    let mut committed_mem: HashMap<(String, usize), u64> = HashMap::new();
+    let mut committed_count: HashMap<(String, usize), usize> = HashMap::new();
+    for job in jobs
+        .iter()
+        .filter(|j| matches!(j.status, JobStatus::Running | JobStatus::Paused))
+    {
+        let node = job.node.clone().unwrap_or_else(|| "local".to_string());
+        for &idx in &job.gpu_indices {
+            let key = (node.clone(), idx);
+            *committed_count.entry(key.clone()).or_insert(0) += 1;
+            *committed_mem.entry(key).or_insert(0) += job.min_mem_mib;
+        }
+    }
+
+    let now = SystemTime::now();
+    let eligible_ids: Vec<String> = if dispatch_new {
+        jobs.iter()
+            .filter(|j| {
+                j.status == JobStatus::Queued
+                    && j.depends_on.iter().all(|id| completed_ids.contains(id))
+                    && j.next_eligible.map_or(true, |t| t <= now)
+            })
+            .map(|j| j.id.clone())
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    for job_id in eligible_ids {
+        let (gpus_required, min_mem_mib) = {
+            let job = jobs.iter().find(|j| j.id == job_id).unwrap();
+            (job.gpus_required, job.min_mem_mib)
+        };
+
+        let assignment: Option<(String, Vec<usize>)> = if gpus_required > 1 {
+            // Multi-GPU jobs claim whole free GPUs on a single node rather than
+            // packing alongside others or spanning multiple hosts.
+            let mut by_node: HashMap<&str, Vec<usize>> = HashMap::new();
+            for g in gpus.iter().filter(|g| {
+                !has_foreign_process(g, &jobs)
+                    && g.memory_total.saturating_sub(g.memory_used) >= config.min_free_memory_mib
+                    && committed_count
+                        .get(&(g.node.clone(), g.index))
+                        .copied()
+                        .unwrap_or(0)
+                        == 0
+            }) {
+                by_node.entry(&g.node).or_default().push(g.index);
+            }
+            // HashMap iteration order is unspecified; sort by node name so
+            // placement is deterministic across scheduling passes instead of
+            // picking a different node run-to-run when several qualify.
+            let mut by_node: Vec<(&str, Vec<usize>)> = by_node.into_iter().collect();
+            by_node.sort_by_key(|(node, _)| *node);
+            by_node
+                .into_iter()
+                .find(|(_, indices)| indices.len() >= gpus_required)
+                .map(|(node, mut indices)| {
+                    indices.sort_unstable();
+                    indices.truncate(gpus_required);
+                    (node.to_string(), indices)
+                })
+        } else {
+            gpus.iter()
+                .find(|g| {
+                    !has_foreign_process(g, &jobs)
+                        && g.memory_total.saturating_sub(g.memory_used) >= config.min_free_memory_mib
+                        && committed_count
+                            .get(&(g.node.clone(), g.index))
+                            .copied()
+                            .unwrap_or(0)
+                            < config.max_jobs_per_gpu
+                        && g.memory_total
+                            .saturating_sub(g.memory_used)
+                            .saturating_sub(
+                                committed_mem
+                                    .get(&(g.node.clone(), g.index))
+                                    .copied()
+                                    .unwrap_or(0),
+                            )
+                            >= min_mem_mib
+                })
+                .map(|g| (g.node.clone(), vec![g.index]))
+        };
+
+        let Some((node, indices)) = assignment else {
+            continue;
+        };
+
+        for &idx in &indices {
+            let key = (node.clone(), idx);
+            *committed_count.entry(key.clone()).or_insert(0) += 1;
+            *committed_mem.entry(key).or_insert(0) += min_mem_mib;
+        }
+
+        let job = jobs.iter_mut().find(|j| j.id == job_id).unwrap();
+        let backend = backend_for(config, Some(node.as_str()));
+        if let Err(e) = backend.start_job(job, indices, config) {
+            eprintln!("{}", format!("Failed to start job {}: {}", job.id, e).red());
+            job.status = JobStatus::Failed;
+            record_history(config, job, None)?;
+            log_service_event(
+                config,
+                &format!("Failed to start job {}: {}", job.id, e),
+            )?;
+        } else {
+            log_service_event(
+                config,
+                &format!(
+                    "Started job {} on node {} GPU(s) {:?}: {}",
+                    job.id, node, job.gpu_indices, job.command
+                ),
+            )?;
+        }
+    }
+
+    update_worker_snapshot(config, &jobs)?;
     save_jobs(&jobs, config)?;
     Ok(())
 }
 
 // Command handlers
-fn handle_add(command: &str, config: &Config) -> io::Result<()> {
+struct AddOptions {
+    depends_on: Vec<String>,
+    gpus_required: usize,
+    min_mem_mib: u64,
+    max_retries: Option<u32>,
+    extra_env: Vec<(String, String)>,
+    cwd: Option<String>,
+}
+
+impl Default for AddOptions {
+    fn default() -> Self {
+        AddOptions {
+            depends_on: Vec::new(),
+            gpus_required: 1,
+            min_mem_mib: 0,
+            max_retries: None,
+            extra_env: Vec::new(),
+            cwd: None,
+        }
+    }
+}
+
+// Walks the depends_on chain looking for new_id. In `handle_add`, `depends_on`
+// may only name jobs that already exist, so a brand-new job's id -- generated
+// after that check -- can never show up as one of its own ancestors; the call
+// there is inert today, kept so the guard is already in place if a later
+// command lets dependencies be edited after creation. It's load-bearing for
+// `handle_add_manifest`, where a manifest's `needs` entries can reference
+// jobs whose ids are only assigned during the same resolution pass.
+fn creates_cycle(jobs: &[Job], new_id: &str, depends_on: &[String]) -> bool {
+    let mut stack: Vec<String> = depends_on.to_vec();
+    let mut seen = HashSet::new();
+    while let Some(id) = stack.pop() {
+        if id == new_id {
+            return true;
+        }
+        if !seen.insert(id.clone()) {
+            continue;
+        }
+        if let Some(job) = jobs.iter().find(|j| j.id == id) {
+            stack.extend(job.depends_on.iter().cloned());
+        }
+    }
+    false
+}
+
+fn handle_add(command: &str, options: AddOptions, config: &Config) -> io::Result<()> {
+    let _lock = lock_state_file(config)?;
     let mut jobs = load_jobs(config)?;
-    let job = create_job(command.to_string());
+
+    if let Some(missing) = options
+        .depends_on
+        .iter()
+        .find(|id| !jobs.iter().any(|j| &j.id == *id))
+    {
+        println!(
+            "{}",
+            format!("Unknown dependency job id: {}", missing).red()
+        );
+        return Ok(());
+    }
+
+    let mut job = create_job(command.to_string());
+
+    if creates_cycle(&jobs, &job.id, &options.depends_on) {
+        println!(
+            "{}",
+            format!("Dependency cycle detected through {:?}", options.depends_on).red()
+        );
+        return Ok(());
+    }
+
+    job.depends_on = options.depends_on;
+    job.gpus_required = options.gpus_required;
+    job.min_mem_mib = options.min_mem_mib;
+    job.max_retries = options.max_retries.unwrap_or(config.default_max_retries);
+    job.extra_env = options.extra_env;
+    job.cwd = options.cwd;
     println!(
         "{} {}",
         "Added job".green(),
@@ -531,81 +1795,1592 @@ fn handle_add(command: &str, config: &Config) -> io::Result<()> {
     save_jobs(&jobs, config)
 }
 
-fn handle_queue(config: &Config) -> io::Result<()> {
-    let jobs = load_jobs(config)?;
-    let queued_jobs: Vec<_> = jobs
-        .iter()
-        .filter(|j| j.status == JobStatus::Queued)
-        .collect();
+// Declarative job manifests (`nexus add -f jobs.toml`)
+//
+// A manifest's `[[job]]` entries reference each other by a local `name`
+// rather than by the generated job id (which doesn't exist until the job is
+// created), so dependency resolution happens in two passes: first every
+// entry is assigned its id(s) -- more than one if it has a `sweep` table --
+// and registered under its name, then `needs` is resolved against that name
+// table to build the real `depends_on` lists. Nothing is written to
+// `state_file` unless the whole manifest resolves cleanly, so a bad manifest
+// never leaves a half-applied batch of jobs in the queue.
+fn toml_scalar_to_string(value: &toml::Value) -> Option<String> {
+    match value {
+        toml::Value::String(s) => Some(s.clone()),
+        toml::Value::Integer(i) => Some(i.to_string()),
+        toml::Value::Float(f) => Some(f.to_string()),
+        toml::Value::Boolean(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+fn apply_template(s: &str, substitutions: &[(String, String)]) -> String {
+    let mut out = s.to_string();
+    for (key, value) in substitutions {
+        out = out.replace(&format!("{{{}}}", key), value);
+    }
+    out
+}
+
+// Cartesian product of every sweep key's values, e.g. `{seed = [1, 2]}` ->
+// `[[("seed", "1")], [("seed", "2")]]`; two keys multiply together.
+fn sweep_combinations(sweep: &[(String, Vec<String>)]) -> Vec<Vec<(String, String)>> {
+    let mut combos: Vec<Vec<(String, String)>> = vec![Vec::new()];
+    for (key, values) in sweep {
+        let mut next = Vec::with_capacity(combos.len() * values.len());
+        for combo in &combos {
+            for value in values {
+                let mut extended = combo.clone();
+                extended.push((key.clone(), value.clone()));
+                next.push(extended);
+            }
+        }
+        combos = next;
+    }
+    combos
+}
+
+struct ManifestJobTemplate {
+    name: String,
+    command: String,
+    needs: Vec<String>,
+    gpus_required: usize,
+    min_mem_mib: u64,
+    max_retries: Option<u32>,
+    cwd: Option<String>,
+    env: Vec<(String, String)>,
+    sweep: Vec<(String, Vec<String>)>,
+}
+
+fn parse_manifest(value: &toml::Value) -> Result<Vec<ManifestJobTemplate>, String> {
+    let defaults = value.get("defaults");
+    let default_gpus = defaults
+        .and_then(|d| d.get("gpus"))
+        .and_then(|v| v.as_integer())
+        .map(|v| v as usize)
+        .unwrap_or(1);
+    let default_min_mem = defaults
+        .and_then(|d| d.get("min_mem_mib"))
+        .and_then(|v| v.as_integer())
+        .map(|v| v as u64)
+        .unwrap_or(0);
+    let default_retries = defaults
+        .and_then(|d| d.get("retries"))
+        .and_then(|v| v.as_integer())
+        .map(|v| v as u32);
+    let default_cwd = defaults
+        .and_then(|d| d.get("cwd"))
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+    let default_env: Vec<(String, String)> = defaults
+        .and_then(|d| d.get("env"))
+        .and_then(|e| e.as_table())
+        .map(|table| {
+            table
+                .iter()
+                .filter_map(|(k, v)| toml_scalar_to_string(v).map(|v| (k.clone(), v)))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let entries = value
+        .get("job")
+        .and_then(|j| j.as_array())
+        .ok_or_else(|| "manifest has no [[job]] entries".to_string())?;
+
+    let mut templates = Vec::new();
+    for (i, entry) in entries.iter().enumerate() {
+        let name = entry
+            .get("name")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| format!("job[{}] missing required string field `name`", i))?
+            .to_string();
+        let command = entry
+            .get("command")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| format!("job[{}] ({}) missing required string field `command`", i, name))?
+            .to_string();
+        let needs = entry
+            .get("needs")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str().map(str::to_string)).collect())
+            .unwrap_or_default();
+        let gpus_required = entry
+            .get("gpus")
+            .and_then(|v| v.as_integer())
+            .map(|v| v as usize)
+            .unwrap_or(default_gpus);
+        if gpus_required == 0 {
+            return Err(format!("job[{}] ({}): gpus must be at least 1", i, name));
+        }
+        let min_mem_mib = entry
+            .get("min_mem_mib")
+            .and_then(|v| v.as_integer())
+            .map(|v| v as u64)
+            .unwrap_or(default_min_mem);
+        let max_retries = entry
+            .get("retries")
+            .and_then(|v| v.as_integer())
+            .map(|v| v as u32)
+            .or(default_retries);
+        let cwd = entry
+            .get("cwd")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+            .or_else(|| default_cwd.clone());
+
+        let mut env = default_env.clone();
+        if let Some(table) = entry.get("env").and_then(|e| e.as_table()) {
+            for (k, v) in table {
+                if let Some(v) = toml_scalar_to_string(v) {
+                    env.retain(|(ek, _)| ek != k);
+                    env.push((k.clone(), v));
+                }
+            }
+        }
+
+        let sweep: Vec<(String, Vec<String>)> = entry
+            .get("sweep")
+            .and_then(|s| s.as_table())
+            .map(|table| {
+                table
+                    .iter()
+                    .filter_map(|(k, v)| {
+                        let values = v.as_array()?.iter().filter_map(toml_scalar_to_string).collect();
+                        Some((k.clone(), values))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        templates.push(ManifestJobTemplate {
+            name,
+            command,
+            needs,
+            gpus_required,
+            min_mem_mib,
+            max_retries,
+            cwd,
+            env,
+            sweep,
+        });
+    }
+
+    Ok(templates)
+}
+
+fn handle_add_manifest(path: &str, config: &Config) -> io::Result<()> {
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(e) => {
+            println!("{}", format!("Could not read manifest {}: {}", path, e).red());
+            return Ok(());
+        }
+    };
+    let value: toml::Value = match toml::from_str(&content) {
+        Ok(v) => v,
+        Err(e) => {
+            println!("{}", format!("Could not parse manifest {}: {}", path, e).red());
+            return Ok(());
+        }
+    };
+    let templates = match parse_manifest(&value) {
+        Ok(t) => t,
+        Err(e) => {
+            println!("{}", format!("Manifest error: {}", e).red());
+            return Ok(());
+        }
+    };
+
+    let _lock = lock_state_file(config)?;
+    let mut jobs = load_jobs(config)?;
+
+    // Pass 1: assign every expansion (sweep or not) an id up front and
+    // register it under its template's name, so `needs` can resolve
+    // regardless of whether it names an entry earlier or later in the file.
+    let mut name_to_ids: HashMap<String, Vec<String>> = HashMap::new();
+    let mut new_jobs: Vec<Job> = Vec::new();
+    for template in &templates {
+        if name_to_ids.contains_key(&template.name) {
+            println!(
+                "{}",
+                format!("Manifest error: duplicate job name `{}`", template.name).red()
+            );
+            return Ok(());
+        }
+        let combos = if template.sweep.is_empty() {
+            vec![Vec::new()]
+        } else {
+            sweep_combinations(&template.sweep)
+        };
+        let mut ids = Vec::with_capacity(combos.len());
+        for combo in combos {
+            let command = apply_template(&template.command, &combo);
+            let mut job = create_job(command);
+            job.gpus_required = template.gpus_required;
+            job.min_mem_mib = template.min_mem_mib;
+            job.max_retries = template.max_retries.unwrap_or(config.default_max_retries);
+            job.cwd = template.cwd.clone();
+            job.extra_env = template
+                .env
+                .iter()
+                .map(|(k, v)| (k.clone(), apply_template(v, &combo)))
+                .collect();
+            ids.push(job.id.clone());
+            new_jobs.push(job);
+        }
+        name_to_ids.insert(template.name.clone(), ids);
+    }
+
+    // Pass 2: resolve `needs` into real depends_on ids and check for cycles
+    // against everything already queued plus everything else in this batch.
+    let mut combined = jobs.clone();
+    for template in &templates {
+        let mut depends_on = Vec::new();
+        for need in &template.needs {
+            match name_to_ids.get(need) {
+                Some(ids) => depends_on.extend(ids.iter().cloned()),
+                None => {
+                    println!(
+                        "{}",
+                        format!(
+                            "Manifest error: job `{}` needs unknown job `{}`",
+                            template.name, need
+                        )
+                        .red()
+                    );
+                    return Ok(());
+                }
+            }
+        }
+
+        for id in &name_to_ids[&template.name] {
+            let job = new_jobs.iter_mut().find(|j| &j.id == id).unwrap();
+            if creates_cycle(&combined, &job.id, &depends_on) {
+                println!(
+                    "{}",
+                    format!("Manifest error: dependency cycle through job `{}`", template.name).red()
+                );
+                return Ok(());
+            }
+            job.depends_on = depends_on.clone();
+            combined.push(job.clone());
+        }
+    }
+
+    let added = new_jobs.len();
+    for job in &new_jobs {
+        println!(
+            "{} {}",
+            "Added job".green(),
+            job.id.to_string().magenta().bold()
+        );
+    }
+    jobs.extend(new_jobs);
+    save_jobs(&jobs, config)?;
+    println!("{}", format!("Added {} job(s) from {}", added, path).green());
+    Ok(())
+}
+
+// `jobs_file` used to be the plaintext queue itself; now that the queue lives
+// in `state_file` as structured records, it's just scratch space for this
+// command to edit queued commands through $EDITOR without hand-rolling a
+// MessagePack-aware editor UI.
+fn handle_edit(config: &Config) -> io::Result<()> {
+    let jobs = load_jobs(config)?;
+    let queued_ids: Vec<&String> = jobs
+        .iter()
+        .filter(|j| j.status == JobStatus::Queued)
+        .map(|j| &j.id)
+        .collect();
+
+    if queued_ids.is_empty() {
+        println!("{}", "No queued jobs to edit.".yellow());
+        return Ok(());
+    }
+
+    let original: String = jobs
+        .iter()
+        .filter(|j| j.status == JobStatus::Queued)
+        .map(|j| format!("{}\t{}\n", j.id, j.command))
+        .collect();
+    fs::write(&config.jobs_file, &original)?;
+
+    let editor = env::var("EDITOR").unwrap_or_else(|_| "vim".to_string());
+    Command::new(editor).arg(&config.jobs_file).status()?;
+
+    let edited = fs::read_to_string(&config.jobs_file)?;
+
+    // The editor can sit open for as long as the user likes, so the daemon may
+    // have dispatched, completed, or retried jobs in the meantime. Take the
+    // lock and re-load the state here, right before folding the edited
+    // command text into a fresh snapshot -- writing back the `jobs` we loaded
+    // before the editor opened would silently revert anything the daemon did
+    // while we were away, and holding the lock across the whole editor
+    // session would block the daemon from making progress the entire time.
+    let _lock = lock_state_file(config)?;
+    let mut jobs = load_jobs(config)?;
+    let mut updated = 0;
+    for line in edited.lines() {
+        let Some((id, command)) = line.split_once('\t') else {
+            continue;
+        };
+        match jobs
+            .iter_mut()
+            .find(|j| j.id == id && j.status == JobStatus::Queued)
+        {
+            Some(job) if job.command != command => {
+                job.command = command.to_string();
+                updated += 1;
+            }
+            Some(_) => {}
+            None => println!("{}", format!("Unknown queued job id: {}", id).red()),
+        }
+    }
+
+    println!("{}", format!("Updated {} job(s).", updated).green());
+    println!(
+        "{}",
+        "Note: adding or removing lines here does not add or delete jobs; use `nexus add`/`nexus remove`."
+            .white()
+    );
+    save_jobs(&jobs, config)
+}
+
+fn print_queued_job(
+    pos: usize,
+    id: &str,
+    command: &str,
+    retry_count: u32,
+    max_retries: u32,
+    next_eligible: Option<SystemTime>,
+    depends_on: &[String],
+) {
+    println!(
+        "{}. {} - {}",
+        (pos + 1).to_string().blue(),
+        id.magenta(),
+        command.white()
+    );
+    if !depends_on.is_empty() {
+        println!("   {} {}", "needs:".cyan(), depends_on.join(", "));
+    }
+    if retry_count > 0 {
+        let wait = next_eligible
+            .and_then(|t| t.duration_since(SystemTime::now()).ok())
+            .filter(|d| !d.is_zero());
+        match wait {
+            Some(d) => println!(
+                "   {} attempt {}/{}, retrying in {}",
+                "retry:".yellow(),
+                retry_count,
+                max_retries,
+                format_duration(d)
+            ),
+            None => println!(
+                "   {} attempt {}/{}, eligible now",
+                "retry:".yellow(),
+                retry_count,
+                max_retries
+            ),
+        }
+    }
+}
+
+// Asks the running daemon for the queue first, and reads the store directly
+// only when no daemon is reachable (e.g. before `start`). The daemon answers
+// from the same `state_file`, so both paths list the same jobs.
+fn handle_queue(config: &Config) -> io::Result<()> {
+    println!("{}", "Pending Jobs:".blue().bold());
+
+    if let Ok(ControlResponse::Queue(queued)) = send_control_request(config, &ControlRequest::Queue) {
+        for (pos, job) in queued.iter().enumerate() {
+            print_queued_job(
+                pos,
+                &job.id,
+                &job.command,
+                job.retry_count,
+                job.max_retries,
+                job.next_eligible,
+                &job.depends_on,
+            );
+        }
+        return Ok(());
+    }
+
+    let jobs = load_jobs(config)?;
+    for (pos, job) in jobs
+        .iter()
+        .filter(|j| j.status == JobStatus::Queued)
+        .enumerate()
+    {
+        print_queued_job(
+            pos,
+            &job.id,
+            &job.command,
+            job.retry_count,
+            job.max_retries,
+            job.next_eligible,
+            &job.depends_on,
+        );
+    }
+    Ok(())
+}
+
+// Shell completion
+//
+// Each command that takes a positional argument declares what kind of thing
+// that argument is; the generated shell scripts call back into `nexus
+// __complete <command>` to turn that into live candidates (real job IDs,
+// valid GPU indices) instead of static words.
+#[derive(Clone, Copy)]
+enum CommandSignature {
+    None,
+    JobId,
+    JobIdOrGpu,
+    JobIdOrGpuOrService,
+}
+
+fn command_signatures() -> Vec<(&'static str, CommandSignature)> {
+    vec![
+        ("kill", CommandSignature::JobIdOrGpu),
+        ("remove", CommandSignature::JobId),
+        ("logs", CommandSignature::JobId),
+        ("attach", CommandSignature::JobIdOrGpuOrService),
+        ("pause", CommandSignature::JobIdOrGpu),
+        ("resume", CommandSignature::JobIdOrGpu),
+        ("config", CommandSignature::None),
+    ]
+}
+
+// Lists completion candidates for `command`'s positional argument on stdout,
+// one per line. This is invoked by the generated shell completion scripts,
+// not typed directly by users.
+fn handle_complete(command: &str, config: &Config) -> io::Result<()> {
+    let signature = command_signatures()
+        .into_iter()
+        .find(|(name, _)| *name == command)
+        .map(|(_, sig)| sig);
+
+    match signature {
+        Some(CommandSignature::JobId) => {
+            for job in load_jobs(config)? {
+                println!("{}", job.id);
+            }
+        }
+        Some(CommandSignature::JobIdOrGpu) | Some(CommandSignature::JobIdOrGpuOrService) => {
+            for job in load_jobs(config)? {
+                println!("{}", job.id);
+            }
+            for gpu in backends(config)
+                .iter()
+                .flat_map(|b| b.gpu_info(config).unwrap_or_default())
+            {
+                println!("{}", gpu.index);
+            }
+            if matches!(signature, Some(CommandSignature::JobIdOrGpuOrService)) {
+                println!("service");
+            }
+        }
+        Some(CommandSignature::None) | None => {}
+    }
+    Ok(())
+}
+
+fn generate_completions(shell: &str) -> io::Result<()> {
+    match shell {
+        "bash" => println!(
+            r#"_nexus_complete() {{
+    local cur cmd
+    cur="${{COMP_WORDS[COMP_CWORD]}}"
+    cmd="${{COMP_WORDS[1]}}"
+    COMPREPLY=($(compgen -W "$(nexus __complete "$cmd" 2>/dev/null)" -- "$cur"))
+}}
+complete -F _nexus_complete nexus"#
+        ),
+        "zsh" => println!(
+            r#"#compdef nexus
+_nexus() {{
+    local cmd="${{words[2]}}"
+    local -a candidates
+    candidates=(${{(f)"$(nexus __complete "$cmd" 2>/dev/null)"}})
+    _describe 'candidate' candidates
+}}
+_nexus"#
+        ),
+        "fish" => println!(
+            r#"function __nexus_complete
+    nexus __complete (commandline -opc)[2] 2>/dev/null
+end
+complete -c nexus -f -a '(__nexus_complete)'"#
+        ),
+        _ => println!("{}", format!("Unsupported shell: {}", shell).red()),
+    }
+    Ok(())
+}
+
+// Builds an isolated `Config` rooted under a per-process, per-scenario temp
+// directory so `nexus __selftest` never touches a real `~/.nexus` install and
+// two runs (e.g. in CI), or two scenarios within the same run, can't collide
+// with each other's state/socket files.
+fn selftest_config(scenario: &str, max_jobs_per_gpu: usize) -> io::Result<Config> {
+    let root = env::temp_dir().join(format!(
+        "nexus-selftest-{}-{}",
+        std::process::id(),
+        scenario
+    ));
+    fs::create_dir_all(&root)?;
+
+    Ok(Config {
+        log_dir: root.join("logs"),
+        jobs_file: root.join("jobs.txt"),
+        state_file: root.join("state.msgpack"),
+        refresh_rate: 1,
+        _colors_enabled: true,
+        datetime_format: "%Y-%m-%d %H:%M:%S".to_string(),
+        min_free_memory_mib: 0,
+        max_jobs_per_gpu,
+        default_max_retries: 0,
+        nodes: Vec::new(),
+        base_retry_delay_secs: 1,
+        kill_grace_secs: 1,
+        history_db: root.join("history.db"),
+        socket_path: root.join("nexus.sock"),
+        stall_timeout_secs: 600,
+        auto_kill_stalled: false,
+        scrub_interval_secs: 3600,
+        drain_timeout_secs: 2,
+    })
+}
+
+// Builds a scriptable job command that exits with `code` after `delay`, so
+// selftest scenarios can assert success/failure/retry paths quickly and
+// hermetically instead of depending on a real, long-running workload.
+fn fake_job_command(code: i32, delay: Duration) -> String {
+    // `(exit N)` sets $? without ending start_job's wrapper shell, so the
+    // wrapper still gets to write N to `exit_code`.
+    format!("sleep {}; (exit {})", delay.as_secs(), code)
+}
+
+// Accumulates pass/fail results for one `nexus __selftest` run, printing each
+// check as it's made so a failure is easy to spot in the middle of a long run.
+struct SelfTest {
+    failures: Vec<String>,
+}
+
+impl SelfTest {
+    fn new() -> Self {
+        SelfTest {
+            failures: Vec::new(),
+        }
+    }
+
+    fn check(&mut self, ok: bool, description: &str) {
+        if ok {
+            println!("  {} {}", "ok".green(), description);
+        } else {
+            println!("  {} {}", "FAIL".red().bold(), description);
+            self.failures.push(description.to_string());
+        }
+    }
+}
+
+// Polls `poll` every 50ms until it returns true or `timeout` elapses, for
+// assertions like "job reached state X within T" against the daemon's own
+// scheduling loop rather than a fixed sleep.
+fn wait_until(timeout: Duration, mut poll: impl FnMut() -> bool) -> bool {
+    let deadline = SystemTime::now() + timeout;
+    loop {
+        if poll() {
+            return true;
+        }
+        if SystemTime::now() >= deadline {
+            return poll();
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
+// Hidden end-to-end harness: drives the real `handle_*`/`process_jobs` code
+// paths against a disposable sandbox config (mock GPUs via `NEXUS_DEV`) to
+// exercise the add -> queue -> dispatch -> pause -> resume -> kill lifecycle
+// without needing a separate test binary or `#[cfg(test)]` target. Exits
+// nonzero (via the `Err` returned here) if any check fails, so it's usable
+// as a CI smoke test: `nexus __selftest`.
+fn handle_selftest() -> io::Result<()> {
+    env::set_var("NEXUS_DEV", "1");
+    let config = selftest_config("lifecycle", 1)?;
+    let mut t = SelfTest::new();
+
+    println!("{}", "Running nexus self-test...".blue().bold());
+
+    handle_add(
+        &fake_job_command(0, Duration::from_secs(5)),
+        AddOptions {
+            gpus_required: 1,
+            ..AddOptions::default()
+        },
+        &config,
+    )?;
+    let jobs = load_jobs(&config)?;
+    t.check(jobs.len() == 1, "job was added");
+    let job_id = jobs[0].id.clone();
+    t.check(
+        jobs[0].status == JobStatus::Queued,
+        "added job starts Queued",
+    );
+
+    process_jobs(&config, true)?;
+    let reached_running = wait_until(Duration::from_secs(5), || {
+        load_jobs(&config)
+            .ok()
+            .and_then(|jobs| jobs.into_iter().find(|j| j.id == job_id))
+            .map(|j| j.status == JobStatus::Running)
+            .unwrap_or(false)
+    });
+    t.check(reached_running, "job dispatched to a mock GPU and is Running");
+
+    handle_job_pause(&job_id, &config)?;
+    let jobs = load_jobs(&config)?;
+    let paused = jobs
+        .iter()
+        .find(|j| j.id == job_id)
+        .map(|j| j.status == JobStatus::Paused)
+        .unwrap_or(false);
+    t.check(paused, "job paused");
+
+    handle_job_resume(&job_id, &config)?;
+    let jobs = load_jobs(&config)?;
+    let running_again = jobs
+        .iter()
+        .find(|j| j.id == job_id)
+        .map(|j| j.status == JobStatus::Running)
+        .unwrap_or(false);
+    t.check(running_again, "job resumed back to Running");
+
+    // force=true is essential here: it skips the interactive confirmation
+    // prompt `handle_kill` would otherwise block on waiting for stdin.
+    handle_kill(&job_id, &config, true)?;
+    let jobs = load_jobs(&config)?;
+    let failed = jobs
+        .iter()
+        .find(|j| j.id == job_id)
+        .map(|j| j.status == JobStatus::Failed)
+        .unwrap_or(false);
+    t.check(failed, "killed job recorded as Failed");
+
+    // Regression coverage: killing an already-finished job must be a no-op,
+    // not re-cancel it and stomp its history row with a fresh end_time.
+    let end_time_before_retry = jobs
+        .iter()
+        .find(|j| j.id == job_id)
+        .and_then(|j| j.end_time);
+    handle_kill(&job_id, &config, true)?;
+    let jobs = load_jobs(&config)?;
+    let end_time_after_retry = jobs
+        .iter()
+        .find(|j| j.id == job_id)
+        .and_then(|j| j.end_time);
+    t.check(
+        end_time_before_retry == end_time_after_retry,
+        "killing an already-finished job is a no-op",
+    );
+
+    handle_history(&config, HistoryFilters::default())?;
+    handle_logs(&job_id, &config, false)?;
+
+    // Regression coverage for the GPU-packing bug: a GPU that already has one
+    // of our own jobs running on it must still be selectable for a second job
+    // once `max_jobs_per_gpu` allows it, rather than being treated as
+    // permanently busy the moment NVML reports its first process.
+    let packing_config = selftest_config("packing", 2)?;
+    handle_add(
+        &fake_job_command(0, Duration::from_secs(5)),
+        AddOptions {
+            gpus_required: 1,
+            ..AddOptions::default()
+        },
+        &packing_config,
+    )?;
+    process_jobs(&packing_config, true)?;
+    let first_running = wait_until(Duration::from_secs(5), || {
+        load_jobs(&packing_config)
+            .ok()
+            .map(|jobs| jobs.iter().any(|j| j.status == JobStatus::Running))
+            .unwrap_or(false)
+    });
+    t.check(first_running, "packing: first job dispatched to the GPU");
+    let first_index = load_jobs(&packing_config)?
+        .into_iter()
+        .find(|j| j.status == JobStatus::Running)
+        .and_then(|j| j.gpu_indices.first().copied());
+
+    handle_add(
+        &fake_job_command(0, Duration::from_secs(5)),
+        AddOptions {
+            gpus_required: 1,
+            ..AddOptions::default()
+        },
+        &packing_config,
+    )?;
+    process_jobs(&packing_config, true)?;
+    let second_running = wait_until(Duration::from_secs(5), || {
+        load_jobs(&packing_config)
+            .ok()
+            .map(|jobs| {
+                jobs.iter()
+                    .filter(|j| j.status == JobStatus::Running)
+                    .count()
+                    == 2
+            })
+            .unwrap_or(false)
+    });
+    t.check(
+        second_running,
+        "packing: second job packed onto the same GPU while the first is still Running",
+    );
+    let packed_onto_same_gpu = load_jobs(&packing_config)?
+        .into_iter()
+        .filter(|j| j.status == JobStatus::Running)
+        .all(|j| j.gpu_indices.first().copied() == first_index);
+    t.check(
+        packed_onto_same_gpu,
+        "packing: both jobs landed on the same GPU index",
+    );
+    for job in load_jobs(&packing_config)? {
+        handle_kill(&job.id, &packing_config, true)?;
+    }
+
+    // Regression coverage for the retry/failure path: a job that exits
+    // nonzero retries up to its max_retries, then lands on Failed once
+    // retries are exhausted.
+    let retry_config = selftest_config("retry", 1)?;
+    handle_add(
+        &fake_job_command(1, Duration::from_secs(1)),
+        AddOptions {
+            gpus_required: 1,
+            max_retries: Some(1),
+            ..AddOptions::default()
+        },
+        &retry_config,
+    )?;
+    let retry_job_id = load_jobs(&retry_config)?[0].id.clone();
+
+    process_jobs(&retry_config, true)?;
+    let retried = wait_until(Duration::from_secs(10), || {
+        process_jobs(&retry_config, true).ok();
+        load_jobs(&retry_config)
+            .ok()
+            .and_then(|jobs| jobs.into_iter().find(|j| j.id == retry_job_id))
+            .map(|j| j.retry_count >= 1)
+            .unwrap_or(false)
+    });
+    t.check(retried, "retry: failing job requeued after its first attempt");
+
+    let failed_after_retry = wait_until(Duration::from_secs(10), || {
+        process_jobs(&retry_config, true).ok();
+        load_jobs(&retry_config)
+            .ok()
+            .and_then(|jobs| jobs.into_iter().find(|j| j.id == retry_job_id))
+            .map(|j| j.status == JobStatus::Failed)
+            .unwrap_or(false)
+    });
+    t.check(
+        failed_after_retry,
+        "retry: job recorded as Failed once retries are exhausted",
+    );
+    let retry_count_exhausted = load_jobs(&retry_config)?
+        .into_iter()
+        .find(|j| j.id == retry_job_id)
+        .map(|j| j.retry_count == 1)
+        .unwrap_or(false);
+    t.check(
+        retry_count_exhausted,
+        "retry: failed job retried exactly once, not repeatedly",
+    );
+
+    if t.failures.is_empty() {
+        println!("{}", "Self-test passed.".green().bold());
+        Ok(())
+    } else {
+        println!(
+            "{}",
+            format!("Self-test failed ({} check(s)):", t.failures.len())
+                .red()
+                .bold()
+        );
+        for failure in &t.failures {
+            println!("  - {}", failure);
+        }
+        Err(io::Error::new(
+            io::ErrorKind::Other,
+            "nexus __selftest reported failures",
+        ))
+    }
+}
+
+// A long-lived runner slot, one per (node, GPU index). Unlike the job-centric
+// `Job`/`JobStatus` model, this tracks the *GPU*'s own lifecycle so a slot that
+// loses contact with its node keeps its last-known-bad state instead of simply
+// vanishing from the report the next time `gpu_info()` is queried.
+#[derive(Clone, Serialize, Deserialize)]
+enum WorkerState {
+    Active {
+        job_id: String,
+        #[serde(with = "system_time_serde")]
+        since: Option<SystemTime>,
+    },
+    Idle,
+    Dead {
+        last_error: String,
+        #[serde(with = "system_time_serde")]
+        at: Option<SystemTime>,
+    },
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct WorkerSnapshot {
+    node: String,
+    index: usize,
+    state: WorkerState,
+}
+
+fn worker_snapshot_path(config: &Config) -> PathBuf {
+    config.log_dir.join("workers.msgpack")
+}
+
+fn load_worker_snapshot(config: &Config) -> Vec<WorkerSnapshot> {
+    let path = worker_snapshot_path(config);
+    if !path.exists() {
+        return Vec::new();
+    }
+    fs::read(&path)
+        .ok()
+        .and_then(|bytes| rmp_serde::from_slice(&bytes).ok())
+        .unwrap_or_default()
+}
+
+fn save_worker_snapshot(config: &Config, snapshot: &[WorkerSnapshot]) -> io::Result<()> {
+    let bytes = rmp_serde::to_vec(snapshot)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+    fs::write(worker_snapshot_path(config), bytes)
+}
+
+// Called once per `process_jobs` pass. Reachable nodes get a fresh Active/Idle
+// entry per GPU; an unreachable node's *existing* entries (we have no fresh
+// index list to enumerate from) flip to Dead with the captured error instead
+// of being silently dropped, so `nexus workers` can still explain why a slot
+// went quiet even when run from outside the daemon.
+fn update_worker_snapshot(config: &Config, jobs: &[Job]) -> io::Result<()> {
+    let mut snapshot = load_worker_snapshot(config);
+    let now = SystemTime::now();
+
+    for backend in backends(config) {
+        match backend.gpu_info(config) {
+            Ok(gpus) => {
+                for gpu in gpus {
+                    let job = jobs.iter().find(|j| {
+                        matches!(j.status, JobStatus::Running | JobStatus::Paused)
+                            && j.gpu_indices.contains(&gpu.index)
+                            && j.node.as_deref().unwrap_or("local") == backend.node_name()
+                    });
+                    let state = match job {
+                        Some(job) => WorkerState::Active {
+                            job_id: job.id.clone(),
+                            since: job.start_time,
+                        },
+                        None => WorkerState::Idle,
+                    };
+                    match snapshot
+                        .iter_mut()
+                        .find(|s| s.node == backend.node_name() && s.index == gpu.index)
+                    {
+                        Some(existing) => existing.state = state,
+                        None => snapshot.push(WorkerSnapshot {
+                            node: backend.node_name().to_string(),
+                            index: gpu.index,
+                            state,
+                        }),
+                    }
+                }
+            }
+            Err(e) => {
+                for existing in snapshot.iter_mut().filter(|s| s.node == backend.node_name()) {
+                    existing.state = WorkerState::Dead {
+                        last_error: e.to_string(),
+                        at: Some(now),
+                    };
+                }
+            }
+        }
+    }
+
+    save_worker_snapshot(config, &snapshot)
+}
+
+// Lists every GPU slot across the cluster as active (with the job running on
+// it), idle, or dead (the last time the daemon reached that node it couldn't
+// query it). Reads the snapshot the daemon persists each scheduling pass
+// rather than live-querying GPUs/jobs, so it works from a one-shot CLI
+// invocation even when nothing is running right now.
+fn handle_workers(config: &Config) -> io::Result<()> {
+    let snapshot = load_worker_snapshot(config);
+    let scrub = load_scrub_status(config);
+    println!("{}", "Workers:".blue().bold());
+
+    if snapshot.is_empty() {
+        println!(
+            "  {}",
+            "No worker data yet -- start the service with `nexus` so it can scan GPUs".yellow()
+        );
+        return Ok(());
+    }
+
+    for worker in &snapshot {
+        let slot = format!("{}:{}", worker.node, worker.index);
+        match &worker.state {
+            WorkerState::Active { job_id, since } => {
+                let runtime = since.map(|t| t.elapsed().unwrap_or_default()).unwrap_or_default();
+                println!(
+                    "  {} [{}]: {} ({})",
+                    slot.white(),
+                    "active".green(),
+                    job_id.magenta(),
+                    format_duration(runtime).to_string().cyan()
+                );
+            }
+            WorkerState::Idle => {
+                println!("  {} [{}]", slot.white(), "idle".bright_green());
+            }
+            WorkerState::Dead { last_error, at } => {
+                let idle = at.map(|t| t.elapsed().unwrap_or_default()).unwrap_or_default();
+                println!(
+                    "  {} [{}]: {} ({} ago)",
+                    slot.white(),
+                    "dead".red(),
+                    last_error.yellow(),
+                    format_duration(idle)
+                );
+            }
+        }
+        for finding in scrub
+            .findings
+            .iter()
+            .filter(|f| f.node == worker.node && f.index == worker.index)
+        {
+            println!("    {} {}", "scrub:".yellow(), finding.message.yellow());
+        }
+    }
+
+    Ok(())
+}
+
+// Manually revives a Dead worker slot back to Idle. For transient failures
+// (a flaky SSH hop, a GPU driver hiccup) the next scheduling pass would
+// naturally overwrite this with a fresh reading anyway, but a node that's
+// been fixed since the daemon last tried it otherwise stays marked Dead until
+// the next pass happens to touch it -- this lets the operator clear it now.
+fn handle_workers_reset(target: &str, config: &Config) -> io::Result<()> {
+    let (node, index) = match target.split_once(':') {
+        Some((node, idx)) => (node.to_string(), idx.parse::<usize>()),
+        None => ("local".to_string(), target.parse::<usize>()),
+    };
+    let Ok(index) = index else {
+        println!("{}", "Usage: nexus workers reset <[node:]index>".red());
+        return Ok(());
+    };
+
+    let mut snapshot = load_worker_snapshot(config);
+    match snapshot
+        .iter_mut()
+        .find(|s| s.node == node && s.index == index)
+    {
+        Some(worker) => {
+            worker.state = WorkerState::Idle;
+            save_worker_snapshot(config, &snapshot)?;
+            println!("{}", format!("Reset worker {}:{} to idle", node, index).green());
+        }
+        None => println!(
+            "{}",
+            format!("No worker recorded for {}:{}", node, index).red()
+        ),
+    }
+    Ok(())
+}
+
+// GPU health scrub
+//
+// A periodic background walk over every GPU looking for things the regular
+// scheduling pass doesn't check for: processes using a GPU that no job claims,
+// or a node that's stopped answering entirely. "Tranquility" (0-10) trades
+// thoroughness for intrusiveness -- after each GPU it checks, the scrub sleeps
+// `tranquility` times as long as that check took, so a high tranquility spreads
+// the same scan out over a much longer wall-clock window instead of hammering
+// every device back-to-back.
+#[derive(Serialize, Deserialize)]
+struct ScrubFinding {
+    node: String,
+    index: usize,
+    message: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ScrubStatus {
+    enabled: bool,
+    tranquility: u8,
+    #[serde(with = "system_time_serde")]
+    last_run: Option<SystemTime>,
+    findings: Vec<ScrubFinding>,
+}
+
+impl Default for ScrubStatus {
+    fn default() -> Self {
+        ScrubStatus {
+            enabled: true,
+            tranquility: 3,
+            last_run: None,
+            findings: Vec::new(),
+        }
+    }
+}
+
+fn scrub_status_path(config: &Config) -> PathBuf {
+    config.log_dir.join("scrub.msgpack")
+}
+
+fn load_scrub_status(config: &Config) -> ScrubStatus {
+    let path = scrub_status_path(config);
+    if !path.exists() {
+        return ScrubStatus::default();
+    }
+    fs::read(&path)
+        .ok()
+        .and_then(|bytes| rmp_serde::from_slice(&bytes).ok())
+        .unwrap_or_default()
+}
+
+fn save_scrub_status(config: &Config, status: &ScrubStatus) -> io::Result<()> {
+    let bytes = rmp_serde::to_vec(status)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+    fs::write(scrub_status_path(config), bytes)
+}
+
+// Walks every GPU on every reachable node once, flagging processes that no
+// known job accounts for and nodes that refused to answer at all. Sleeps
+// between GPUs in proportion to `tranquility` so a cautious operator can run
+// this without competing with real scheduling traffic.
+fn run_scrub_pass(config: &Config, tranquility: u8) -> io::Result<Vec<ScrubFinding>> {
+    let jobs = load_jobs(config)?;
+    let mut findings = Vec::new();
+
+    for backend in backends(config) {
+        let gpus = match backend.gpu_info(config) {
+            Ok(gpus) => gpus,
+            Err(e) => {
+                findings.push(ScrubFinding {
+                    node: backend.node_name().to_string(),
+                    index: 0,
+                    message: format!("node unreachable: {}", e),
+                });
+                continue;
+            }
+        };
+
+        for gpu in gpus {
+            let check_started = SystemTime::now();
+
+            let claimed = jobs.iter().any(|j| {
+                matches!(j.status, JobStatus::Running | JobStatus::Paused)
+                    && j.gpu_indices.contains(&gpu.index)
+                    && j.node.as_deref().unwrap_or("local") == backend.node_name()
+            });
+            if !claimed && !gpu.processes.is_empty() {
+                for proc in &gpu.processes {
+                    findings.push(ScrubFinding {
+                        node: backend.node_name().to_string(),
+                        index: gpu.index,
+                        message: format!(
+                            "orphaned process {} (pid {}) using a GPU nexus has no job assigned to",
+                            proc.owner, proc.pid
+                        ),
+                    });
+                }
+            }
+            if gpu.memory_used > gpu.memory_total {
+                findings.push(ScrubFinding {
+                    node: backend.node_name().to_string(),
+                    index: gpu.index,
+                    message: format!(
+                        "reported memory_used ({} MiB) exceeds memory_total ({} MiB)",
+                        gpu.memory_used, gpu.memory_total
+                    ),
+                });
+            }
+
+            if tranquility > 0 {
+                let elapsed = check_started.elapsed().unwrap_or_default();
+                thread::sleep(elapsed * tranquility as u32);
+            }
+        }
+    }
+
+    Ok(findings)
+}
+
+fn handle_scrub(args: &[String], config: &Config) -> io::Result<()> {
+    const USAGE: &str = "Usage: nexus scrub <start|pause|cancel|tranquility <0-10>>";
+    let mut status = load_scrub_status(config);
+
+    match args.first().map(String::as_str) {
+        Some("start") => {
+            status.enabled = true;
+            save_scrub_status(config, &status)?;
+            println!("{}", "GPU health scrub enabled".green());
+        }
+        Some("pause") => {
+            status.enabled = false;
+            save_scrub_status(config, &status)?;
+            println!("{}", "GPU health scrub paused".yellow());
+        }
+        Some("cancel") => {
+            status = ScrubStatus {
+                enabled: false,
+                ..ScrubStatus::default()
+            };
+            save_scrub_status(config, &status)?;
+            println!("{}", "GPU health scrub cancelled and findings cleared".yellow());
+        }
+        Some("tranquility") => {
+            let Some(n) = args.get(1).and_then(|v| v.parse::<u8>().ok()).filter(|n| *n <= 10) else {
+                println!("{}", USAGE.red());
+                return Ok(());
+            };
+            status.tranquility = n;
+            save_scrub_status(config, &status)?;
+            println!("{}", format!("Scrub tranquility set to {}", n).green());
+        }
+        _ => println!("{}", USAGE.red()),
+    }
+
+    Ok(())
+}
+
+// Interactive dashboard: three live-refreshing panes (GPUs, queue, history)
+// with keybindings that act on the selected row via the same handlers the
+// one-shot CLI commands use.
+fn handle_tui(config: &Config) -> io::Result<()> {
+    use crossterm::{
+        event::{self, Event, KeyCode},
+        execute,
+        terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    };
+    use ratatui::{
+        backend::CrosstermBackend,
+        layout::{Constraint, Direction, Layout},
+        style::{Color, Modifier, Style},
+        widgets::{Block, Borders, List, ListItem, ListState},
+        Terminal,
+    };
+
+    const PANES: usize = 3; // GPUs, queue, history
+
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+
+    // Aggregates GPUs across every configured node, not just the local
+    // machine, so the GPU pane reflects where jobs are actually dispatched.
+    let fetch_gpus = |config: &Config| -> Vec<GpuInfo> {
+        backends(config)
+            .iter()
+            .flat_map(|b| b.gpu_info(config).unwrap_or_default())
+            .collect()
+    };
+
+    let mut pane = 0usize;
+    let mut selected = [0usize; PANES];
+    let mut jobs = load_jobs(config)?;
+    let mut gpus = fetch_gpus(config);
+    let mut last_refresh = SystemTime::now();
+
+    loop {
+        if last_refresh.elapsed().unwrap_or_default() >= Duration::from_secs(config.refresh_rate.max(1))
+        {
+            jobs = load_jobs(config)?;
+            gpus = fetch_gpus(config);
+            last_refresh = SystemTime::now();
+        }
+
+        let queued: Vec<&Job> = jobs.iter().filter(|j| j.status == JobStatus::Queued).collect();
+        let history: Vec<&Job> = jobs
+            .iter()
+            .filter(|j| {
+                matches!(
+                    j.status,
+                    JobStatus::Completed | JobStatus::Failed | JobStatus::Interrupted
+                )
+            })
+            .collect();
+        for (i, len) in [gpus.len(), queued.len(), history.len()].into_iter().enumerate() {
+            if len > 0 {
+                selected[i] = selected[i].min(len - 1);
+            } else {
+                selected[i] = 0;
+            }
+        }
+
+        terminal.draw(|f| {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Percentage(40),
+                    Constraint::Percentage(30),
+                    Constraint::Percentage(30),
+                ])
+                .split(f.size());
+
+            let gpu_items: Vec<ListItem> = gpus
+                .iter()
+                .map(|g| {
+                    let running = jobs.iter().find(|j| {
+                        j.status == JobStatus::Running && j.gpu_indices.contains(&g.index)
+                    });
+                    ListItem::new(match running {
+                        Some(j) => format!("GPU {} ({}): {} - {}", g.index, g.name, j.id, j.command),
+                        None => format!("GPU {} ({}): idle", g.index, g.name),
+                    })
+                })
+                .collect();
+            let queue_items: Vec<ListItem> = queued
+                .iter()
+                .map(|j| ListItem::new(format!("{}: {}", j.id, j.command)))
+                .collect();
+            let history_items: Vec<ListItem> = history
+                .iter()
+                .map(|j| ListItem::new(format!("{}: {} ({:?})", j.id, j.command, j.status)))
+                .collect();
+
+            let titles = ["GPUs", "Queue", "History"];
+            for (i, items) in [gpu_items, queue_items, history_items].into_iter().enumerate() {
+                let mut state = ListState::default();
+                if !items.is_empty() {
+                    state.select(Some(selected[i]));
+                }
+                let highlight = if i == pane {
+                    Style::default().add_modifier(Modifier::REVERSED)
+                } else {
+                    Style::default()
+                };
+                let list = List::new(items)
+                    .block(
+                        Block::default()
+                            .title(titles[i])
+                            .borders(Borders::ALL)
+                            .border_style(if i == pane {
+                                Style::default().fg(Color::Cyan)
+                            } else {
+                                Style::default()
+                            }),
+                    )
+                    .highlight_style(highlight);
+                f.render_stateful_widget(list, chunks[i], &mut state);
+            }
+        })?;
+
+        if event::poll(Duration::from_millis(250))? {
+            if let Event::Key(key) = event::read()? {
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => break,
+                    KeyCode::Tab => pane = (pane + 1) % PANES,
+                    KeyCode::Down => selected[pane] += 1,
+                    KeyCode::Up => selected[pane] = selected[pane].saturating_sub(1),
+                    KeyCode::Char('k') => {
+                        // History holds only Completed/Failed/Interrupted jobs --
+                        // there's nothing left to kill there, so the binding is a
+                        // no-op rather than risking re-cancelling a finished job.
+                        let target = match pane {
+                            0 => gpus.get(selected[0]).map(|g| g.index.to_string()),
+                            1 => queued.get(selected[1]).map(|j| j.id.clone()),
+                            _ => None,
+                        };
+                        if let Some(target) = target {
+                            // Selecting a row and pressing k is itself the confirming
+                            // gesture, so skip the interactive y/N prompt here.
+                            handle_kill(&target, config, true)?;
+                        }
+                    }
+                    KeyCode::Char('d') => {
+                        if pane == 1 {
+                            if let Some(job) = queued.get(selected[1]) {
+                                handle_remove(&job.id, config, true)?;
+                            }
+                        }
+                    }
+                    KeyCode::Char('p') => {
+                        if send_control_request(config, &ControlRequest::Pause).is_err() {
+                            fs::write(config.log_dir.join("paused"), "")?;
+                        }
+                    }
+                    KeyCode::Char('r') => {
+                        if send_control_request(config, &ControlRequest::Resume).is_err() {
+                            let _ = fs::remove_file(config.log_dir.join("paused"));
+                        }
+                    }
+                    KeyCode::Enter => {
+                        let target = match pane {
+                            0 => gpus.get(selected[0]).map(|g| g.index.to_string()),
+                            1 => queued.get(selected[1]).map(|j| j.id.clone()),
+                            _ => history.get(selected[2]).map(|j| j.id.clone()),
+                        };
+                        if let Some(target) = target {
+                            disable_raw_mode()?;
+                            execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                            let _ = handle_attach(&target, None, config);
+                            enable_raw_mode()?;
+                            execute!(terminal.backend_mut(), EnterAlternateScreen)?;
+                            terminal.clear()?;
+                        }
+                    }
+                    _ => {}
+                }
+                jobs = load_jobs(config)?;
+                gpus = fetch_gpus(config);
+            }
+        }
+    }
+
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    Ok(())
+}
+
+// Filters for `nexus history`, applied as a SQL WHERE clause against the
+// history database rather than scanning jobs in memory.
+#[derive(Default)]
+struct HistoryFilters {
+    status: Option<String>,
+    gpu: Option<usize>,
+    since: Option<Duration>,
+    limit: Option<u32>,
+    search: Option<String>,
+}
+
+fn open_history_db(config: &Config) -> rusqlite::Result<Connection> {
+    let conn = Connection::open(&config.history_db)?;
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS history (
+            id TEXT PRIMARY KEY,
+            command TEXT NOT NULL,
+            gpu_indices TEXT NOT NULL,
+            status TEXT NOT NULL,
+            exit_code INTEGER,
+            start_time INTEGER,
+            end_time INTEGER,
+            log_dir TEXT
+        )",
+    )?;
+    Ok(conn)
+}
 
-    println!("{}", "Pending Jobs:".blue().bold());
-    for (pos, job) in queued_jobs.iter().enumerate() {
-        println!(
-            "{}. {} - {}",
-            (pos + 1).to_string().blue(),
-            job.id.magenta(),
-            job.command.white()
-        );
-    }
+// Upserts a job's row once it reaches a terminal status (Completed, Failed,
+// or Interrupted), so `nexus history` can query the database instead of replaying
+// the full jobs state on every call.
+fn record_history(config: &Config, job: &Job, exit_code: Option<i32>) -> io::Result<()> {
+    let conn = open_history_db(config).map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+    let to_secs = |t: SystemTime| t.duration_since(UNIX_EPOCH).ok().map(|d| d.as_secs() as i64);
+    conn.execute(
+        "INSERT OR REPLACE INTO history
+            (id, command, gpu_indices, status, exit_code, start_time, end_time, log_dir)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        params![
+            job.id,
+            job.command,
+            job.gpu_indices
+                .iter()
+                .map(|i| i.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+            format!("{:?}", job.status),
+            exit_code,
+            job.start_time.and_then(to_secs),
+            job.end_time.and_then(to_secs),
+            job.log_dir.as_ref().map(|p| p.display().to_string()),
+        ],
+    )
+    .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
     Ok(())
 }
 
-fn handle_history(config: &Config) -> io::Result<()> {
-    let jobs = load_jobs(config)?;
-    println!("{}", "Completed Jobs:".blue().bold());
-    for job in jobs.iter().filter(|j| j.status == JobStatus::Completed) {
-        let runtime = job
-            .start_time
-            .map(|t| t.elapsed().unwrap_or_default())
-            .unwrap_or_default();
+fn handle_history(config: &Config, filters: HistoryFilters) -> io::Result<()> {
+    let conn = open_history_db(config).map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+
+    let mut clauses = Vec::new();
+    let mut query_params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+
+    if let Some(status) = &filters.status {
+        let normalized = match status.to_lowercase().as_str() {
+            "failed" => "Failed",
+            "done" | "completed" => "Completed",
+            "interrupted" => "Interrupted",
+            other => other,
+        };
+        clauses.push("status = ?".to_string());
+        query_params.push(Box::new(normalized.to_string()));
+    }
+    if let Some(gpu) = filters.gpu {
+        clauses.push("(',' || gpu_indices || ',') LIKE ?".to_string());
+        query_params.push(Box::new(format!("%,{},%", gpu)));
+    }
+    if let Some(since) = filters.since {
+        let cutoff = SystemTime::now()
+            .checked_sub(since)
+            .unwrap_or(UNIX_EPOCH)
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs() as i64;
+        clauses.push("end_time >= ?".to_string());
+        query_params.push(Box::new(cutoff));
+    }
+    if let Some(search) = &filters.search {
+        clauses.push("command LIKE ?".to_string());
+        query_params.push(Box::new(format!("%{}%", search)));
+    }
+
+    let mut sql =
+        "SELECT id, command, gpu_indices, status, exit_code, start_time, end_time FROM history"
+            .to_string();
+    if !clauses.is_empty() {
+        sql.push_str(" WHERE ");
+        sql.push_str(&clauses.join(" AND "));
+    }
+    sql.push_str(" ORDER BY end_time DESC");
+    if let Some(limit) = filters.limit {
+        sql.push_str(&format!(" LIMIT {}", limit));
+    }
+
+    let mut stmt = conn
+        .prepare(&sql)
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+    let param_refs: Vec<&dyn rusqlite::ToSql> = query_params.iter().map(|p| p.as_ref()).collect();
+
+    let rows = stmt
+        .query_map(param_refs.as_slice(), |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, Option<i64>>(4)?,
+                row.get::<_, Option<i64>>(5)?,
+                row.get::<_, Option<i64>>(6)?,
+            ))
+        })
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+
+    println!("{}", "Job History:".blue().bold());
+    for row in rows {
+        let (id, command, gpus, status, exit_code, start, end) =
+            row.map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+        let runtime = match (start, end) {
+            (Some(s), Some(e)) if e >= s => Duration::from_secs((e - s) as u64),
+            _ => Duration::default(),
+        };
         println!(
-            "{}: {} (Runtime: {}, GPU: {})",
-            job.id.magenta(),
-            job.command.white(),
+            "{}: {} (Runtime: {}, GPU: {}, Status: {}, Exit: {})",
+            id.magenta(),
+            command.white(),
             format_duration(runtime).to_string().cyan(),
-            job.gpu_index
-                .map(|i| i.to_string())
-                .unwrap_or_else(|| "Unknown".to_string())
-                .yellow()
+            if gpus.is_empty() {
+                "Unknown".to_string()
+            } else {
+                gpus
+            }
+            .yellow(),
+            status,
+            exit_code
+                .map(|c| c.to_string())
+                .unwrap_or_else(|| "-".to_string())
         );
     }
     Ok(())
 }
 
-fn handle_kill(target: &str, config: &Config) -> io::Result<()> {
+// Prompts on stdin for a y/N confirmation before a destructive action.
+fn confirm(message: &str) -> io::Result<bool> {
+    print!("{} [y/N] ", message.yellow());
+    io::stdout().flush()?;
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(matches!(input.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+fn handle_kill(target: &str, config: &Config, force: bool) -> io::Result<()> {
+    let _lock = lock_state_file(config)?;
     let mut jobs = load_jobs(config)?;
 
-    // Try as GPU index first
+    // Try as GPU index first: a GPU can have more than one job packed onto it
+    // (max_jobs_per_gpu > 1), so every active job there needs to be named and
+    // confirmed, not just the first match.
     if let Ok(gpu_index) = target.parse::<usize>() {
-        if let Some(job) = jobs
-            .iter_mut()
-            .find(|j| j.status == JobStatus::Running && j.gpu_index == Some(gpu_index))
-        {
-            if let Some(session) = &job.screen_session {
-                Command::new("screen")
-                    .args(["-S", session, "-X", "quit"])
-                    .output()?;
-                job.status = JobStatus::Completed;
+        let running_ids: Vec<String> = jobs
+            .iter()
+            .filter(|j| {
+                matches!(j.status, JobStatus::Running | JobStatus::Paused)
+                    && j.gpu_indices.contains(&gpu_index)
+            })
+            .map(|j| j.id.clone())
+            .collect();
+
+        if !running_ids.is_empty() {
+            if !force {
                 println!(
-                    "{} {} {}",
-                    "Killed job".green(),
-                    job.id.magenta(),
-                    format!("on GPU {}", gpu_index).yellow()
+                    "{}",
+                    format!(
+                        "This will cancel {} running job(s) on GPU {}: {}",
+                        running_ids.len(),
+                        gpu_index,
+                        running_ids.join(", ")
+                    )
+                    .yellow()
                 );
-                save_jobs(&jobs, config)?;
-                return Ok(());
+                if !confirm("Proceed?")? {
+                    println!("{}", "Aborted.".red());
+                    return Ok(());
+                }
+            }
+            for id in &running_ids {
+                if let Some(job) = jobs.iter_mut().find(|j| &j.id == id) {
+                    cancel_job(job, config)?;
+                }
             }
+            println!(
+                "{} {} {}",
+                "Cancelled job(s)".green(),
+                running_ids.join(", ").magenta(),
+                format!("on GPU {}", gpu_index).yellow()
+            );
+            save_jobs(&jobs, config)?;
+            return Ok(());
         }
     }
 
     // Try as job ID
     if let Some(job) = jobs.iter_mut().find(|j| j.id == target) {
-        if let Some(session) = &job.screen_session {
-            Command::new("screen")
-                .args(["-S", session, "-X", "quit"])
-                .output()?;
-            job.status = JobStatus::Completed;
-            println!("{} {}", "Killed job".green(), job.id.magenta());
+        if job.screen_session.is_some() && matches!(job.status, JobStatus::Running | JobStatus::Paused) {
+            if !force
+                && !confirm(&format!(
+                    "Job {} ({}) is running. Cancel it?",
+                    job.id, job.command
+                ))?
+            {
+                println!("{}", "Aborted.".red());
+                return Ok(());
+            }
+            let id = job.id.clone();
+            cancel_job(job, config)?;
+            println!("{} {}", "Cancelled job".green(), id.magenta());
             save_jobs(&jobs, config)?;
             return Ok(());
         }
@@ -618,7 +3393,94 @@ fn handle_kill(target: &str, config: &Config) -> io::Result<()> {
     Ok(())
 }
 
-fn handle_remove(id: &str, config: &Config) -> io::Result<()> {
+// Suspends a single running job in place with SIGSTOP to its process group,
+// rather than the global `nexus pause` which only stops new launches. The
+// job keeps its GPU reservation and screen session; `process_jobs` skips
+// Paused jobs entirely so it won't mistake the suspended session for a
+// finished one.
+fn handle_job_pause(target: &str, config: &Config) -> io::Result<()> {
+    let _lock = lock_state_file(config)?;
+    let mut jobs = load_jobs(config)?;
+
+    let ids: Vec<String> = if let Ok(gpu_index) = target.parse::<usize>() {
+        jobs.iter()
+            .filter(|j| j.status == JobStatus::Running && j.gpu_indices.contains(&gpu_index))
+            .map(|j| j.id.clone())
+            .collect()
+    } else {
+        jobs.iter()
+            .filter(|j| j.id == target && j.status == JobStatus::Running)
+            .map(|j| j.id.clone())
+            .collect()
+    };
+
+    if ids.is_empty() {
+        println!(
+            "{}",
+            format!("No running job found with ID or GPU: {}", target).red()
+        );
+        return Ok(());
+    }
+
+    for id in &ids {
+        let job = jobs.iter_mut().find(|j| &j.id == id).unwrap();
+        let backend = backend_for(config, job.node.as_deref());
+        if let Some(log_dir) = job.log_dir.clone() {
+            backend.signal_job(&log_dir, "STOP")?;
+        }
+        job.status = JobStatus::Paused;
+    }
+
+    println!("{} {}", "Paused job(s)".yellow(), ids.join(", ").magenta());
+    save_jobs(&jobs, config)
+}
+
+fn handle_job_resume(target: &str, config: &Config) -> io::Result<()> {
+    let _lock = lock_state_file(config)?;
+    let mut jobs = load_jobs(config)?;
+
+    let ids: Vec<String> = if let Ok(gpu_index) = target.parse::<usize>() {
+        jobs.iter()
+            .filter(|j| j.status == JobStatus::Paused && j.gpu_indices.contains(&gpu_index))
+            .map(|j| j.id.clone())
+            .collect()
+    } else {
+        jobs.iter()
+            .filter(|j| j.id == target && j.status == JobStatus::Paused)
+            .map(|j| j.id.clone())
+            .collect()
+    };
+
+    if ids.is_empty() {
+        println!(
+            "{}",
+            format!("No paused job found with ID or GPU: {}", target).red()
+        );
+        return Ok(());
+    }
+
+    for id in &ids {
+        let job = jobs.iter_mut().find(|j| &j.id == id).unwrap();
+        let backend = backend_for(config, job.node.as_deref());
+        if let Some(log_dir) = job.log_dir.clone() {
+            backend.signal_job(&log_dir, "CONT")?;
+        }
+        job.status = JobStatus::Running;
+    }
+
+    println!(
+        "{} {}",
+        "Resumed job(s)".green(),
+        ids.join(", ").magenta()
+    );
+    save_jobs(&jobs, config)
+}
+
+// `force` is accepted for CLI symmetry with `kill`, but remove never touches
+// a Running job in the first place (it only ever pulls from the Queued
+// list), so there is nothing here for a confirmation guard to protect.
+fn handle_remove(id: &str, config: &Config, _force: bool) -> io::Result<()> {
+    let _lock = lock_state_file(config)?;
     let mut jobs = load_jobs(config)?;
     if let Some(pos) = jobs
         .iter()
@@ -637,24 +3499,68 @@ fn handle_logs(id: &str, config: &Config, follow: bool) -> io::Result<()> {
     let jobs = load_jobs(config)?;
     if let Some(job) = jobs.iter().find(|j| j.id == id) {
         if let Some(log_dir) = &job.log_dir {
-            if follow && job.status == JobStatus::Running {
-                // Use tail -f for following logs
-                Command::new("tail")
-                    .args([
-                        "-f",
-                        log_dir.join("stdout.log").to_str().unwrap(),
-                        log_dir.join("stderr.log").to_str().unwrap(),
-                    ])
-                    .status()?;
-            } else {
-                println!("{}", "=== STDOUT ===".blue().bold());
-                if let Ok(content) = fs::read_to_string(log_dir.join("stdout.log")) {
-                    println!("{}", content);
+            match job.node.as_deref() {
+                None | Some("local") => {
+                    if follow && job.status == JobStatus::Running {
+                        // Use tail -f for following logs
+                        Command::new("tail")
+                            .args([
+                                "-f",
+                                log_dir.join("stdout.log").to_str().unwrap(),
+                                log_dir.join("stderr.log").to_str().unwrap(),
+                            ])
+                            .status()?;
+                    } else {
+                        println!("{}", "=== STDOUT ===".blue().bold());
+                        if let Ok(content) = fs::read_to_string(log_dir.join("stdout.log")) {
+                            println!("{}", content);
+                        }
+
+                        println!("\n{}", "=== STDERR ===".red().bold());
+                        if let Ok(content) = fs::read_to_string(log_dir.join("stderr.log")) {
+                            println!("{}", content);
+                        }
+                    }
                 }
+                Some(node_name) => {
+                    let Some(host) = config
+                        .nodes
+                        .iter()
+                        .find(|n| n.name == node_name)
+                        .map(|n| n.host.clone())
+                    else {
+                        println!("{}", format!("Unknown node: {}", node_name).red());
+                        return Ok(());
+                    };
+                    if follow && job.status == JobStatus::Running {
+                        Command::new("ssh")
+                            .args([
+                                "-t",
+                                &host,
+                                &format!(
+                                    "tail -f {}/stdout.log {}/stderr.log",
+                                    log_dir.display(),
+                                    log_dir.display()
+                                ),
+                            ])
+                            .status()?;
+                    } else {
+                        println!("{}", "=== STDOUT ===".blue().bold());
+                        if let Ok(output) = Command::new("ssh")
+                            .args([&host, &format!("cat {}/stdout.log", log_dir.display())])
+                            .output()
+                        {
+                            println!("{}", String::from_utf8_lossy(&output.stdout));
+                        }
 
-                println!("\n{}", "=== STDERR ===".red().bold());
-                if let Ok(content) = fs::read_to_string(log_dir.join("stderr.log")) {
-                    println!("{}", content);
+                        println!("\n{}", "=== STDERR ===".red().bold());
+                        if let Ok(output) = Command::new("ssh")
+                            .args([&host, &format!("cat {}/stderr.log", log_dir.display())])
+                            .output()
+                        {
+                            println!("{}", String::from_utf8_lossy(&output.stdout));
+                        }
+                    }
                 }
             }
         } else {
@@ -666,27 +3572,121 @@ fn handle_logs(id: &str, config: &Config, follow: bool) -> io::Result<()> {
     Ok(())
 }
 
-fn handle_attach(target: &str) -> io::Result<()> {
-    let session_name = if target == "service" {
-        "nexus".to_string()
+// Lists every live nexus-managed screen session: job id, GPU(s), node, and
+// uptime, plus whether the session is actually still alive or just stale
+// state nexus hasn't reaped yet.
+fn handle_sessions(config: &Config) -> io::Result<()> {
+    let jobs = load_jobs(config)?;
+    println!("{}", "Sessions:".blue().bold());
+    for job in jobs
+        .iter()
+        .filter(|j| matches!(j.status, JobStatus::Running | JobStatus::Paused))
+    {
+        let Some(session) = &job.screen_session else {
+            continue;
+        };
+        let backend = backend_for(config, job.node.as_deref());
+        let alive = backend.is_job_running(session);
+        let uptime = job
+            .start_time
+            .map(|t| t.elapsed().unwrap_or_default())
+            .unwrap_or_default();
+        let state = if job.status == JobStatus::Paused {
+            "paused".yellow()
+        } else if alive {
+            "alive".green()
+        } else {
+            "dead".red()
+        };
+        println!(
+            "{}: {} (GPU {:?}, node {}, uptime {}) [{}]",
+            job.id.magenta(),
+            session,
+            job.gpu_indices,
+            job.node.as_deref().unwrap_or("local"),
+            format_duration(uptime),
+            state
+        );
+    }
+    Ok(())
+}
+
+// Exits 0 if `id` names a live running session, 1 otherwise, for scripting
+// (`nexus has <id> && ...`).
+fn handle_has(id: &str, config: &Config) -> io::Result<()> {
+    let jobs = load_jobs(config)?;
+    let alive = jobs.iter().any(|j| {
+        j.id == id
+            && j.status == JobStatus::Running
+            && j.screen_session.as_deref().is_some_and(|s| {
+                backend_for(config, j.node.as_deref()).is_job_running(s)
+            })
+    });
+    std::process::exit(if alive { 0 } else { 1 });
+}
+
+fn handle_attach(target: &str, window: Option<&str>, config: &Config) -> io::Result<()> {
+    let jobs = load_jobs(config)?;
+
+    let (session_name, node) = if target == "service" {
+        ("nexus".to_string(), None)
     } else if let Ok(gpu_index) = target.parse::<usize>() {
-        format!("nexus_job_gpu_{}", gpu_index)
+        match jobs
+            .iter()
+            .find(|j| j.status == JobStatus::Running && j.gpu_indices.contains(&gpu_index))
+        {
+            Some(job) => (format!("nexus_job_{}", job.id), job.node.clone()),
+            None => {
+                println!(
+                    "{}",
+                    format!("No running job found on GPU {}", gpu_index).red()
+                );
+                return Ok(());
+            }
+        }
     } else {
-        format!("nexus_job_{}", target)
+        (
+            format!("nexus_job_{}", target),
+            jobs.iter()
+                .find(|j| j.id == target)
+                .and_then(|j| j.node.clone()),
+        )
     };
 
-    if is_job_running(&session_name) {
-        Command::new("screen")
-            .args(["-r", &session_name])
-            .status()?;
-        Ok(())
-    } else {
+    let backend = backend_for(config, node.as_deref());
+    if !backend.is_job_running(&session_name) {
         println!(
             "{}",
             format!("No running session found for {}", target).red()
         );
-        Ok(())
+        return Ok(());
+    }
+
+    match node.as_deref() {
+        None | Some("local") => {
+            let mut cmd = Command::new("screen");
+            cmd.arg("-r").arg(&session_name);
+            if let Some(window) = window {
+                cmd.args(["-p", window]);
+            }
+            cmd.status()?;
+        }
+        Some(node_name) => {
+            if let Some(host) = config
+                .nodes
+                .iter()
+                .find(|n| n.name == node_name)
+                .map(|n| n.host.clone())
+            {
+                let mut remote_command = format!("screen -r {}", session_name);
+                if let Some(window) = window {
+                    remote_command.push_str(&format!(" -p {}", window));
+                }
+                Command::new("ssh").args(["-t", &host, &remote_command]).status()?;
+            }
+        }
     }
+    Ok(())
 }
 
 fn handle_config(_config: &Config) -> io::Result<()> {
@@ -697,6 +3697,31 @@ fn handle_config(_config: &Config) -> io::Result<()> {
     Ok(())
 }
 
+fn handle_config_check(_config: &Config) -> io::Result<()> {
+    let home = dirs::home_dir().unwrap();
+    let config_path = home.join(".nexus/config.toml");
+    let content = fs::read_to_string(&config_path)?;
+
+    let value: toml::Value = match toml::from_str(&content) {
+        Ok(v) => v,
+        Err(e) => {
+            println!("{}", format!("Config parse error: {}", e).red());
+            return Ok(());
+        }
+    };
+
+    let problems = validate_config(&value);
+    if problems.is_empty() {
+        println!("{}", "Config OK".green());
+    } else {
+        println!("{}", format!("{} problem(s) found:", problems.len()).red());
+        for problem in problems {
+            println!("  {}", problem.yellow());
+        }
+    }
+    Ok(())
+}
+
 fn handle_config_edit() -> io::Result<()> {
     let home = dirs::home_dir().unwrap();
     let config_path = home.join(".nexus/config.toml");
@@ -712,20 +3737,36 @@ fn print_help() {
 {}:
     nexus                     Show status
     nexus -n                 Non-interactive status
-    nexus stop               Stop the nexus service
-    nexus restart            Restart the nexus service
+    nexus stop               Stop the nexus service immediately
+    nexus stop --drain [--timeout N]   Stop gracefully: wait (up to N secs) for running jobs to finish
+    nexus restart            Reload the running daemon in place, or start it if not running
+    nexus reload             Reload the running daemon in place (no-op if not running)
     nexus add \"command\"      Add job to queue
+    nexus add --after <id> --gpus N --min-mem <MiB> \"command\"   Add job with requirements
+    nexus add -f <manifest.toml>   Add multiple jobs from a declarative manifest
     nexus queue              Show pending jobs
-    nexus history            Show completed jobs
-    nexus kill <id|gpu>      Kill job by ID or GPU number
+    nexus history [--status failed|done] [--gpu N] [--since <duration>] [--limit N] [--search <text>]
+                             Show job history, optionally filtered
+    nexus workers            List each GPU slot as active, idle, or dead
+    nexus workers reset <[node:]index>   Revive a dead worker slot to idle
+    nexus scrub start|pause|cancel   Control the background GPU health scrub
+    nexus scrub tranquility <0-10>   How gently the scrub paces itself between GPUs
+    nexus tui                Live dashboard (alias: top)
+    nexus completions <bash|zsh|fish>   Print a shell completion script
+    nexus kill <id|gpu> [--force|-y]   Gracefully cancel job by ID or GPU number
     nexus remove <id>        Remove job from queue
-    nexus pause              Pause queue processing
+    nexus pause              Pause queue processing (no new launches)
+    nexus pause <id|gpu>     Suspend a running job in place (SIGSTOP)
     nexus resume             Resume queue processing
+    nexus resume <id|gpu>    Resume a suspended job (SIGCONT)
     nexus logs <id> [-f]     View logs for job
-    nexus attach <id|gpu>    Attach to running job's screen session
-    nexus edit               Open jobs.txt in $EDITOR
+    nexus attach <id|gpu|service> [window]   Attach to a job's session, optionally a specific window
+    nexus sessions           List all live nexus-managed sessions
+    nexus has <id>           Exit 0/1 depending on whether <id> is running (for scripting)
+    nexus edit               Edit queued job commands in $EDITOR
     nexus config             View current config
     nexus config edit        Edit config.toml in $EDITOR
+    nexus config check       Validate config.toml and report all problems
     nexus help               Show this help
     nexus help <command>     Show detailed help for command",
         "Nexus: GPU Job Management CLI".green().bold(),
@@ -736,22 +3777,64 @@ fn print_help() {
 fn print_command_help(command: &str) {
     match command {
         "add" => println!(
-            "{}\nAdd a new job to the queue. Enclose command in quotes.",
-            "nexus add \"command\"".green()
+            "{}\nAdd a new job to the queue. Enclose command in quotes.\n\n\
+`nexus add -f <manifest.toml>` adds several jobs at once from a declarative manifest:\n\
+  [defaults]                     # gpus, min_mem_mib, retries, cwd, env -- shared unless a job overrides them\n\
+  [[job]]\n\
+  name = \"train\"                # local name, used by `needs` -- not the generated job id\n\
+  command = \"python train.py --seed {{seed}}\"\n\
+  needs = [\"prep\"]              # other job names in this file; forward references are fine\n\
+  [job.sweep]\n\
+  seed = [1, 2, 3]                # expands this entry into one job per value, substituting {{seed}}\n\
+A manifest with a job missing `name` or `command`, `gpus = 0`, a duplicate name, an unknown `needs` target, or a dependency cycle adds nothing at all; other fields of the wrong type fall back to their defaults.",
+            "nexus add \"command\" | nexus add -f <manifest.toml>".green()
+        ),
+        "stop" => println!(
+            "{}\nStop the nexus service. Plain `nexus stop` (or `--now`) tears down the daemon's screen session immediately. `--drain` instead signals the running daemon to stop dispatching new jobs and wait for running ones to finish on their own, up to `drain_timeout_secs` (or the `--timeout N` override) before interrupting whatever's left.",
+            "nexus stop [--now | --drain [--timeout N]]".green()
         ),
         "kill" => println!(
-            "{}\nKill a running job by its ID or GPU number.",
-            "nexus kill <id|gpu>".green()
+            "{}\nGracefully cancel a running job by its ID or GPU number: SIGTERM its process group, wait kill_grace_secs, then SIGKILL if it hasn't exited. Prompts for confirmation unless --force/-y is given.",
+            "nexus kill <id|gpu> [--force|-y]".green()
+        ),
+        "history" => println!(
+            "{}\nShow job history from the SQLite-backed history database. Filters: --status failed|done, --gpu N, --since <duration> (e.g. 12h), --limit N, --search <substring of command>.",
+            "nexus history [--status ...] [--gpu N] [--since ...] [--limit N] [--search ...]".green()
+        ),
+        "workers" => println!(
+            "{}\nList every GPU slot across the cluster as active (with the job running there), idle, or dead (the daemon's last attempt to reach that node failed), reading the snapshot the daemon persists each scheduling pass. `nexus workers reset <[node:]index>` clears a dead slot back to idle.",
+            "nexus workers [reset <[node:]index>]".green()
+        ),
+        "scrub" => println!(
+            "{}\nBackground health check that periodically walks every GPU looking for orphaned processes nexus has no job for, and nodes that stop answering. `start`/`pause`/`cancel` control whether the daemon keeps auto-triggering it (cancel also clears past findings); `tranquility <0-10>` sets how long it sleeps between GPU checks, proportional to how long each check took, so higher values spread the same scan out more gently.",
+            "nexus scrub <start|pause|cancel|tranquility <0-10>>".green()
+        ),
+        "completions" => println!(
+            "{}\nPrint a completion script for the given shell. Tab-completion of job IDs and GPU indices calls back into `nexus __complete` for live candidates.",
+            "nexus completions <bash|zsh|fish>".green()
+        ),
+        "tui" => println!(
+            "{}\nFull-screen dashboard with live GPU/queue/history panes. Tab switches pane, Up/Down moves selection, k kills, d removes, p/r pause/resume the queue, Enter attaches, q/Esc quits.",
+            "nexus tui".green()
         ),
         "attach" => println!(
-            "{}\nAttach to a running job's screen session. Use Ctrl+A+D to detach.",
-            "nexus attach <id|gpu>".green()
+            "{}\nAttach to a running job's session by ID, GPU index, or 'service'. Use Ctrl+A+D to detach. An optional window argument focuses a specific window within the session.",
+            "nexus attach <id|gpu|service> [window]".green()
+        ),
+        "sessions" => println!(
+            "{}\nList every live nexus-managed session with its job id, GPU(s), node, and uptime.",
+            "nexus sessions".green()
+        ),
+        "has" => println!(
+            "{}\nExit 0 if <id> is a currently running job, 1 otherwise. For scripting.",
+            "nexus has <id>".green()
         ),
         "config" => println!(
-            "{}\n{}\nView current configuration.\n{}\nEdit configuration in $EDITOR.",
+            "{}\n{}\nView current configuration.\n{}\nEdit configuration in $EDITOR.\n{}\nValidate config.toml, reporting every unknown key or wrong-typed field found.",
             "Configuration:".blue().bold(),
             "nexus config".green(),
-            "nexus config edit".green()
+            "nexus config edit".green(),
+            "nexus config check".green()
         ),
         _ => println!(
             "{}",
@@ -760,30 +3843,292 @@ fn print_command_help(command: &str) {
     }
 }
 
+// Daemon control socket
+//
+// `add`/`remove`/`kill` still go through the structured job store in
+// `state_file`, same as every other command -- that's made race-free by
+// `lock_state_file`'s flock around every load_jobs/save_jobs pass, not by
+// going through this socket. What file polling couldn't give us is an
+// immediate, queryable view of the running daemon itself, so the control
+// socket covers the commands that are actually about the daemon's live
+// state: pausing/resuming the scheduler and asking whether it's even up, plus
+// a queue listing read from `state_file`. Messages are length-prefixed
+// (u32 LE) MessagePack; `state_file` holds the same MessagePack encoding
+// without a length prefix.
+#[derive(Serialize, Deserialize)]
+enum ControlRequest {
+    Ping,
+    Status,
+    Pause,
+    Resume,
+    Queue,
+}
+
+#[derive(Serialize, Deserialize)]
+struct QueuedJobSummary {
+    id: String,
+    command: String,
+    retry_count: u32,
+    max_retries: u32,
+    #[serde(with = "system_time_serde")]
+    next_eligible: Option<SystemTime>,
+    depends_on: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+enum ControlResponse {
+    Pong,
+    Status { paused: bool },
+    Queue(Vec<QueuedJobSummary>),
+    Ok,
+}
+
+fn write_control_message<T: Serialize>(stream: &mut UnixStream, message: &T) -> io::Result<()> {
+    let bytes = rmp_serde::to_vec(message)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+    stream.write_all(&(bytes.len() as u32).to_le_bytes())?;
+    stream.write_all(&bytes)
+}
+
+fn read_control_message<T: for<'de> Deserialize<'de>>(stream: &mut UnixStream) -> io::Result<T> {
+    let mut len_bytes = [0u8; 4];
+    stream.read_exact(&mut len_bytes)?;
+    let mut bytes = vec![0u8; u32::from_le_bytes(len_bytes) as usize];
+    stream.read_exact(&mut bytes)?;
+    rmp_serde::from_slice(&bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))
+}
+
+// Sends a request to the running daemon's control socket. Returns an error
+// (and the caller falls back to the old file-based behavior) when no daemon
+// is listening, so `nexus pause`/`resume` keep working even before the
+// service is started.
+fn send_control_request(config: &Config, request: &ControlRequest) -> io::Result<ControlResponse> {
+    let mut stream = UnixStream::connect(&config.socket_path)?;
+    write_control_message(&mut stream, request)?;
+    read_control_message(&mut stream)
+}
+
+fn handle_control_connection(
+    mut stream: UnixStream,
+    config: &Config,
+    paused: &Arc<AtomicBool>,
+) -> io::Result<()> {
+    let request: ControlRequest = read_control_message(&mut stream)?;
+    let response = match request {
+        ControlRequest::Ping => ControlResponse::Pong,
+        ControlRequest::Status => ControlResponse::Status {
+            paused: paused.load(Ordering::SeqCst),
+        },
+        ControlRequest::Pause => {
+            paused.store(true, Ordering::SeqCst);
+            fs::write(config.log_dir.join("paused"), "")?;
+            ControlResponse::Ok
+        }
+        ControlRequest::Resume => {
+            paused.store(false, Ordering::SeqCst);
+            let _ = fs::remove_file(config.log_dir.join("paused"));
+            ControlResponse::Ok
+        }
+        ControlRequest::Queue => {
+            let jobs = load_jobs(config)?;
+            ControlResponse::Queue(
+                jobs.iter()
+                    .filter(|j| j.status == JobStatus::Queued)
+                    .map(|j| QueuedJobSummary {
+                        id: j.id.clone(),
+                        command: j.command.clone(),
+                        retry_count: j.retry_count,
+                        max_retries: j.max_retries,
+                        next_eligible: j.next_eligible,
+                        depends_on: j.depends_on.clone(),
+                    })
+                    .collect(),
+            )
+        }
+    };
+    write_control_message(&mut stream, &response)
+}
+
+fn serve_control_socket(config: Config, paused: Arc<AtomicBool>) -> io::Result<()> {
+    let _ = fs::remove_file(&config.socket_path);
+    let listener = UnixListener::bind(&config.socket_path)?;
+    for conn in listener.incoming() {
+        match conn {
+            Ok(stream) => {
+                if let Err(e) = handle_control_connection(stream, &config, &paused) {
+                    let _ = log_service_event(&config, &format!("Control socket error: {}", e));
+                }
+            }
+            Err(e) => {
+                let _ = log_service_event(&config, &format!("Control socket accept error: {}", e));
+            }
+        }
+    }
+    Ok(())
+}
+
 fn run_daemon(config: &Config) -> io::Result<()> {
+    fs::write(daemon_pid_path(config), std::process::id().to_string())?;
+
     let running = Arc::new(AtomicBool::new(true));
     let r = running.clone();
+    let draining = Arc::new(AtomicBool::new(false));
+    let drain_deadline: Arc<Mutex<Option<SystemTime>>> = Arc::new(Mutex::new(None));
 
-    // Set up signal handler
-    let mut signals = Signals::new(&[SIGTERM])?;
+    // Set up signal handler.
+    //
+    // SIGHUP re-execs the daemon in place (see `handle_reload`) instead of
+    // stopping it, so `nexus reload` never leaves a gap with no daemon alive.
+    //
+    // SIGTERM/SIGINT enter a drain instead of stopping outright: the main
+    // loop stops dispatching new jobs but keeps running until every
+    // in-flight job finishes or `config.drain_timeout_secs` (or a `nexus
+    // stop --drain --timeout` override, read once via `read_drain_timeout`)
+    // elapses, at which point whatever's left is interrupted and the daemon
+    // exits. A second SIGTERM/SIGINT while already draining stops right
+    // away, for operators who change their mind about waiting.
+    //
+    // SIGCHLD reaps any direct child this process spawns without waiting on
+    // synchronously. Every `Command` call here blocks via `.output()`/
+    // `.status()` today, so in practice there's nothing to reap -- job
+    // processes run detached under `screen` and are never this process's
+    // children -- but this keeps that invariant from silently rotting into
+    // zombies if a future change spawns one without waiting.
+    let reload_config = config.clone();
+    let signal_draining = draining.clone();
+    let signal_deadline = drain_deadline.clone();
+    let mut signals = Signals::new(&[SIGTERM, SIGHUP, SIGINT, SIGCHLD])?;
     thread::spawn(move || {
-        for _ in signals.forever() {
-            r.store(false, Ordering::SeqCst);
+        for signal in signals.forever() {
+            match signal {
+                SIGHUP => {
+                    let _ = log_service_event(&reload_config, "Reload requested, re-executing daemon");
+                    let err = Command::new(env::current_exe().unwrap_or_else(|_| "nexus".into()))
+                        .arg("daemon")
+                        .exec();
+                    let _ = log_service_event(&reload_config, &format!("Reload exec failed: {}", err));
+                }
+                SIGCHLD => unsafe {
+                    while waitpid(-1, std::ptr::null_mut(), WNOHANG) > 0 {}
+                },
+                _ => {
+                    if signal_draining.swap(true, Ordering::SeqCst) {
+                        let _ = log_service_event(
+                            &reload_config,
+                            "Second shutdown signal received, stopping immediately",
+                        );
+                        r.store(false, Ordering::SeqCst);
+                    } else {
+                        let timeout = read_drain_timeout(&reload_config);
+                        *signal_deadline.lock().unwrap() = Some(SystemTime::now() + timeout);
+                        let _ = log_service_event(
+                            &reload_config,
+                            &format!(
+                                "Drain requested, waiting up to {} for running jobs",
+                                format_duration(timeout)
+                            ),
+                        );
+                    }
+                }
+            }
         }
     });
 
     log_service_event(config, "Service started")?;
 
-    let gpus = get_gpu_info()?;
-    log_service_event(config, &format!("Found {} GPUs", gpus.len()))?;
+    let gpu_count: usize = backends(config)
+        .iter()
+        .map(|b| b.gpu_info(config).map(|g| g.len()).unwrap_or(0))
+        .sum();
+    log_service_event(config, &format!("Found {} GPUs", gpu_count))?;
 
     // Recover any running jobs from previous sessions
     recover_running_jobs()?;
 
+    let paused = Arc::new(AtomicBool::new(config.log_dir.join("paused").exists()));
+    {
+        let socket_config = config.clone();
+        let socket_paused = paused.clone();
+        thread::spawn(move || {
+            if let Err(e) = serve_control_socket(socket_config.clone(), socket_paused) {
+                let _ = log_service_event(
+                    &socket_config,
+                    &format!("Control socket listener stopped: {}", e),
+                );
+            }
+        });
+    }
+
+    {
+        let scrub_config = config.clone();
+        let scrub_running = running.clone();
+        thread::spawn(move || {
+            while scrub_running.load(Ordering::SeqCst) {
+                thread::sleep(Duration::from_secs(5));
+                let status = load_scrub_status(&scrub_config);
+                let due = status
+                    .last_run
+                    .map(|t| t.elapsed().unwrap_or_default().as_secs() >= scrub_config.scrub_interval_secs)
+                    .unwrap_or(true);
+                if !status.enabled || !due {
+                    continue;
+                }
+                match run_scrub_pass(&scrub_config, status.tranquility) {
+                    Ok(findings) => {
+                        let _ = log_service_event(
+                            &scrub_config,
+                            &format!("GPU health scrub finished: {} finding(s)", findings.len()),
+                        );
+                        let _ = save_scrub_status(
+                            &scrub_config,
+                            &ScrubStatus {
+                                enabled: status.enabled,
+                                tranquility: status.tranquility,
+                                last_run: Some(SystemTime::now()),
+                                findings,
+                            },
+                        );
+                    }
+                    Err(e) => {
+                        let _ = log_service_event(&scrub_config, &format!("GPU health scrub failed: {}", e));
+                    }
+                }
+            }
+        });
+    }
+
     let mut last_check = SystemTime::now();
     while running.load(Ordering::SeqCst) {
+        // While draining, keep polling for completion independently of the
+        // pause flag and refresh cadence below: a paused queue or a slow
+        // refresh_rate shouldn't delay noticing the drain is done.
+        if draining.load(Ordering::SeqCst) {
+            // Reconcile without dispatching new jobs, so jobs that finish on
+            // their own during the drain still get marked Completed/Failed
+            // instead of being caught Running/Paused by the timeout below and
+            // wrongly marked Interrupted.
+            process_jobs(config, false)?;
+            let timed_out = drain_deadline
+                .lock()
+                .unwrap()
+                .is_some_and(|deadline| SystemTime::now() >= deadline);
+            let still_running = load_jobs(config)?
+                .iter()
+                .any(|j| matches!(j.status, JobStatus::Running | JobStatus::Paused));
+
+            if timed_out && still_running {
+                drain_running_jobs(config)?;
+            }
+            if timed_out || !still_running {
+                running.store(false, Ordering::SeqCst);
+                continue;
+            }
+            thread::sleep(Duration::from_millis(500));
+            continue;
+        }
+
         // Check if paused
-        if config.log_dir.join("paused").exists() {
+        if paused.load(Ordering::SeqCst) {
             thread::sleep(Duration::from_secs(1));
             continue;
         }
@@ -801,13 +4146,16 @@ fn run_daemon(config: &Config) -> io::Result<()> {
         last_check = SystemTime::now();
 
         // Process jobs
-        if let Err(e) = process_jobs(config) {
+        if let Err(e) = process_jobs(config, true) {
             log_service_event(config, &format!("Error processing jobs: {}", e))?;
             // Add small delay to prevent rapid error logging
             thread::sleep(Duration::from_secs(1));
         }
     }
 
+    let _ = fs::remove_file(&config.socket_path);
+    let _ = fs::remove_file(daemon_pid_path(config));
+    let _ = fs::remove_file(drain_timeout_override_path(config));
     log_service_event(config, "Service stopped")?;
     Ok(())
 }
@@ -822,47 +4170,192 @@ fn main() -> io::Result<()> {
             handle_status(&config)
         }
         Some("-n") => handle_status(&config),
-        Some("stop") => stop_service(),
+        Some("stop") => {
+            let mode = if args.get(2).map(String::as_str) == Some("--drain") {
+                let timeout = if args.get(3).map(String::as_str) == Some("--timeout") {
+                    args.get(4).and_then(|s| s.parse().ok())
+                } else {
+                    None
+                };
+                StopMode::Drain { timeout }
+            } else {
+                StopMode::Now
+            };
+            stop_service(mode, &config)
+        }
         Some("restart") => {
-            stop_service()?;
-            thread::sleep(Duration::from_secs(1));
-            start_service(&config)
+            if handle_reload(&config)? {
+                Ok(())
+            } else {
+                // No daemon alive to reload in place -- nothing running jobs
+                // could lose, so a plain start is just as safe here.
+                start_service(&config)
+            }
+        }
+        Some("reload") => {
+            if !handle_reload(&config)? {
+                println!("{}", "Nexus service is not running".red());
+            }
+            Ok(())
         }
         Some("add") => {
-            if args.len() < 3 {
-                println!("{}", "Usage: nexus add \"command\"".red());
+            const ADD_USAGE: &str = "Usage: nexus add [--after <id>,<id>] [--gpus N] [--min-mem <MiB>] [--retries N] \"command\"\n       nexus add -f <manifest.toml>";
+            if args.get(2).map(String::as_str) == Some("-f") {
+                if args.len() < 4 {
+                    println!("{}", ADD_USAGE.red());
+                    Ok(())
+                } else {
+                    handle_add_manifest(&args[3], &config)
+                }
+            } else if args.len() < 3 {
+                println!("{}", ADD_USAGE.red());
                 Ok(())
             } else {
-                handle_add(&args[2..].join(" "), &config)
+                let mut rest = &args[2..];
+                let mut options = AddOptions::default();
+                loop {
+                    match rest.first().map(|s| s.as_str()) {
+                        Some("--after") if rest.len() >= 3 => {
+                            options.depends_on =
+                                rest[1].split(',').map(|s| s.to_string()).collect();
+                            rest = &rest[2..];
+                        }
+                        Some("--gpus") if rest.len() >= 3 => {
+                            options.gpus_required = match rest[1].parse() {
+                                Ok(0) | Err(_) => {
+                                    println!("{}", ADD_USAGE.red());
+                                    return Ok(());
+                                }
+                                Ok(n) => n,
+                            };
+                            rest = &rest[2..];
+                        }
+                        Some("--min-mem") if rest.len() >= 3 => {
+                            options.min_mem_mib = match rest[1].parse() {
+                                Ok(n) => n,
+                                Err(_) => {
+                                    println!("{}", ADD_USAGE.red());
+                                    return Ok(());
+                                }
+                            };
+                            rest = &rest[2..];
+                        }
+                        Some("--retries") if rest.len() >= 3 => {
+                            options.max_retries = match rest[1].parse() {
+                                Ok(n) => Some(n),
+                                Err(_) => {
+                                    println!("{}", ADD_USAGE.red());
+                                    return Ok(());
+                                }
+                            };
+                            rest = &rest[2..];
+                        }
+                        _ => break,
+                    }
+                }
+                handle_add(&rest.join(" "), options, &config)
             }
         }
         Some("queue") => handle_queue(&config),
-        Some("history") => handle_history(&config),
+        Some("workers") => {
+            if args.get(2).map(String::as_str) == Some("reset") {
+                if args.len() < 4 {
+                    println!("{}", "Usage: nexus workers reset <[node:]index>".red());
+                    Ok(())
+                } else {
+                    handle_workers_reset(&args[3], &config)
+                }
+            } else {
+                handle_workers(&config)
+            }
+        }
+        Some("scrub") => handle_scrub(&args[2..], &config),
+        Some("tui") | Some("top") => handle_tui(&config),
+        Some("completions") => {
+            if args.len() < 3 {
+                println!("{}", "Usage: nexus completions <bash|zsh|fish>".red());
+                Ok(())
+            } else {
+                generate_completions(&args[2])
+            }
+        }
+        Some("__complete") => {
+            if args.len() < 3 {
+                Ok(())
+            } else {
+                handle_complete(&args[2], &config)
+            }
+        }
+        Some("__selftest") => handle_selftest(),
+        Some("history") => {
+            let mut filters = HistoryFilters::default();
+            let mut rest = &args[2..];
+            loop {
+                match rest.first().map(|s| s.as_str()) {
+                    Some("--status") if rest.len() >= 2 => {
+                        filters.status = Some(rest[1].clone());
+                        rest = &rest[2..];
+                    }
+                    Some("--gpu") if rest.len() >= 2 => {
+                        filters.gpu = rest[1].parse().ok();
+                        rest = &rest[2..];
+                    }
+                    Some("--since") if rest.len() >= 2 => {
+                        filters.since = parse_duration(&rest[1]).ok();
+                        rest = &rest[2..];
+                    }
+                    Some("--limit") if rest.len() >= 2 => {
+                        filters.limit = rest[1].parse().ok();
+                        rest = &rest[2..];
+                    }
+                    Some("--search") if rest.len() >= 2 => {
+                        filters.search = Some(rest[1].clone());
+                        rest = &rest[2..];
+                    }
+                    _ => break,
+                }
+            }
+            handle_history(&config, filters)
+        }
         Some("kill") => {
             if args.len() < 3 {
-                println!("{}", "Usage: nexus kill <id|gpu>".red());
+                println!("{}", "Usage: nexus kill <id|gpu> [--force|-y]".red());
                 Ok(())
             } else {
-                handle_kill(&args[2], &config)
+                let force = args[3..].iter().any(|a| a == "--force" || a == "-y");
+                handle_kill(&args[2], &config, force)
             }
         }
         Some("remove") => {
             if args.len() < 3 {
-                println!("{}", "Usage: nexus remove <id>".red());
+                println!("{}", "Usage: nexus remove <id> [--force|-y]".red());
                 Ok(())
             } else {
-                handle_remove(&args[2], &config)
+                let force = args[3..].iter().any(|a| a == "--force" || a == "-y");
+                handle_remove(&args[2], &config, force)
             }
         }
         Some("pause") => {
-            fs::write(config.log_dir.join("paused"), "")?;
-            println!("{}", "Queue processing paused".yellow());
-            Ok(())
+            if args.len() > 2 {
+                handle_job_pause(&args[2], &config)
+            } else {
+                if send_control_request(&config, &ControlRequest::Pause).is_err() {
+                    fs::write(config.log_dir.join("paused"), "")?;
+                }
+                println!("{}", "Queue processing paused".yellow());
+                Ok(())
+            }
         }
         Some("resume") => {
-            fs::remove_file(config.log_dir.join("paused"))?;
-            println!("{}", "Queue processing resumed".green());
-            Ok(())
+            if args.len() > 2 {
+                handle_job_resume(&args[2], &config)
+            } else {
+                if send_control_request(&config, &ControlRequest::Resume).is_err() {
+                    let _ = fs::remove_file(config.log_dir.join("paused"));
+                }
+                println!("{}", "Queue processing resumed".green());
+                Ok(())
+            }
         }
         Some("logs") => {
             if args.len() < 3 {
@@ -875,20 +4368,27 @@ fn main() -> io::Result<()> {
         }
         Some("attach") => {
             if args.len() < 3 {
-                println!("{}", "Usage: nexus attach <id|gpu|service>".red());
+                println!("{}", "Usage: nexus attach <id|gpu|service> [window]".red());
                 Ok(())
             } else {
-                handle_attach(&args[2])
+                handle_attach(&args[2], args.get(3).map(|s| s.as_str()), &config)
             }
         }
-        Some("edit") => {
-            let editor = env::var("EDITOR").unwrap_or_else(|_| "vim".to_string());
-            Command::new(editor).arg(&config.jobs_file).status()?;
-            Ok(())
+        Some("sessions") => handle_sessions(&config),
+        Some("has") => {
+            if args.len() < 3 {
+                println!("{}", "Usage: nexus has <id>".red());
+                Ok(())
+            } else {
+                handle_has(&args[2], &config)
+            }
         }
+        Some("edit") => handle_edit(&config),
         Some("config") => {
             if args.len() > 2 && args[2] == "edit" {
                 handle_config_edit()
+            } else if args.len() > 2 && args[2] == "check" {
+                handle_config_check(&config)
             } else {
                 handle_config(&config)
             }